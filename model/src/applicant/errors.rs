@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GradeComponentError {
+    #[error("Failed to parse grade contribution: \"{0}\"")]
+    Contribution(String),
+
+    #[error("Failed to parse grade formula: \"{0}\"")]
+    Formula(String),
+
+    #[error("Unknown grade subject: \"{0}\"")]
+    Subject(String),
+}
@@ -0,0 +1,187 @@
+use crate::applicant::errors::GradeComponentError;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::{Display, EnumString};
+
+// Shape version of `scraped.applicant.grade_components`. Bump it together with a
+// migration rewriting stored rows whenever `GradeComponents` changes.
+pub const GRADE_COMPONENTS_VERSION: u8 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GradeComponents {
+    pub version: u8,
+    pub components: Vec<GradeComponent>,
+}
+
+impl GradeComponents {
+    pub const fn new(components: Vec<GradeComponent>) -> Self {
+        Self {
+            version: GRADE_COMPONENTS_VERSION,
+            components,
+        }
+    }
+}
+
+// One term of the competitive score, e.g. kv "+26.800" with formula "134 x 0.2"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GradeComponent {
+    // From the label after kv's number ("+26.800 (ЄВІ)"), when EDBO gives one
+    pub subject: Option<GradeSubject>,
+    // Missing for bonuses and for data imported from `edbo_core`, which kept only kv
+    pub formula: Option<GradeFormula>,
+    // Weighted contribution to the competitive score
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub contribution: BigDecimal,
+}
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display,
+)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum GradeSubject {
+    #[strum(serialize = "ЄВІ")]
+    ForeignLanguageTest,
+
+    #[strum(serialize = "ЄФВВ")]
+    ProfessionalTest,
+
+    #[strum(serialize = "Фахове випробування")]
+    InstitutionExam,
+
+    #[strum(serialize = "Творчий конкурс")]
+    CreativeContest,
+
+    #[strum(serialize = "Додаткові бали")]
+    AdditionalPoints,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GradeFormula {
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub raw_score: BigDecimal,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub coefficient: BigDecimal,
+}
+
+impl GradeComponent {
+    // kv is the number, then optionally the test's label: "+26.800 (ЄВІ)". `edbo_core`
+    // kept only the number.
+    pub fn from_edbo(
+        contribution: &str, formula: &str,
+    ) -> Result<Self, GradeComponentError> {
+        let (number, label) = contribution
+            .trim()
+            .split_once(char::is_whitespace)
+            .unwrap_or_else(|| (contribution.trim(), ""));
+        let subject = Self::parse_subject(label)?;
+        let contribution = Self::parse_number(number.trim_start_matches('+'))
+            .ok_or_else(|| GradeComponentError::Contribution(contribution.to_string()))?;
+
+        let formula = formula.trim();
+        let formula = if formula.is_empty() {
+            None
+        } else {
+            Some(
+                GradeFormula::parse(formula)
+                    .ok_or_else(|| GradeComponentError::Formula(formula.to_string()))?,
+            )
+        };
+
+        Ok(Self {
+            subject,
+            formula,
+            contribution,
+        })
+    }
+
+    // Raw test score if known, the contribution otherwise. The coefficient depends on
+    // the offer, so only the raw score identifies a test result across applications.
+    pub fn score(&self) -> &BigDecimal {
+        self.formula
+            .as_ref()
+            .map_or(&self.contribution, |formula| &formula.raw_score)
+    }
+
+    // An unknown label fails like a malformed formula: it's a test we don't model yet
+    fn parse_subject(label: &str) -> Result<Option<GradeSubject>, GradeComponentError> {
+        let label = label
+            .trim()
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .trim();
+        if label.is_empty() {
+            return Ok(None);
+        }
+
+        GradeSubject::from_str(label)
+            .map(Some)
+            .map_err(|_| GradeComponentError::Subject(label.to_string()))
+    }
+
+    fn parse_number(value: &str) -> Option<BigDecimal> {
+        BigDecimal::from_str(&value.trim().replace(',', ".")).ok()
+    }
+}
+
+impl GradeFormula {
+    // "134 x 0.2", "134 × 0.2" or a bare "134" (coefficient 1)
+    fn parse(formula: &str) -> Option<Self> {
+        let mut parts = formula.split(['x', 'х', '×', '*']);
+        let raw_score = GradeComponent::parse_number(parts.next()?)?;
+        let coefficient = match parts.next() {
+            Some(coefficient) => GradeComponent::parse_number(coefficient)?,
+            None => BigDecimal::from(1),
+        };
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            raw_score,
+            coefficient,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_edbo_formulas() -> Result<(), GradeComponentError> {
+        let component = GradeComponent::from_edbo("+26.800", "134 x 0.2")?;
+        assert_eq!(component.contribution, BigDecimal::new(268.into(), 1));
+        assert_eq!(
+            component.formula,
+            Some(GradeFormula {
+                raw_score: BigDecimal::from(134),
+                coefficient: BigDecimal::new(2.into(), 1),
+            })
+        );
+
+        let bonus = GradeComponent::from_edbo("+10", "")?;
+        assert_eq!(bonus.formula, None);
+        assert_eq!(bonus.score(), &BigDecimal::from(10));
+
+        assert_eq!(component.subject, None);
+
+        let annotated = GradeComponent::from_edbo(" +26.800 (ЄВІ)", "134 x 0.2")?;
+        assert_eq!(annotated.subject, Some(GradeSubject::ForeignLanguageTest));
+        assert_eq!(annotated.contribution, component.contribution);
+        assert_eq!(annotated.formula, component.formula);
+
+        let exam = GradeComponent::from_edbo("+60.000 Фахове випробування", "150 x 0.4")?;
+        assert_eq!(exam.subject, Some(GradeSubject::InstitutionExam));
+
+        assert!(GradeComponent::from_edbo("+26.800 (Олімпіада)", "134 x 0.2").is_err());
+        assert!(GradeComponent::from_edbo("+26.800", "134 x").is_err());
+        assert!(GradeComponent::from_edbo("", "134 x 0.2").is_err());
+        assert!(GradeComponent::from_edbo("n/a", "134 x 0.2").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use crate::applicant::grade::GradeComponent;
+use bigdecimal::BigDecimal;
+
+// EDBO publishes applications, not applicants. The same person shows up once per offer
+// they applied to, and namesakes are common, so applications are clustered here using
+// everything that identifies a person: name, EDBO id (when present) and test scores.
+
+// Share of comparable grade components that must agree to treat two applications with the
+// same name as one person. Master's applicants share the ЄВІ/ЄФВВ scores across all their
+// applications, while the professional test differs between institutions.
+const SAME_PERSON_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct ApplicationEvidence {
+    pub name: String,
+    pub edbo_id: Option<i32>,
+    pub grade_components: Vec<GradeComponent>,
+}
+
+#[derive(Debug)]
+pub struct ResolvedApplicant {
+    pub name: String,
+    pub edbo_id: Option<i32>,
+    // Indexes into the resolved evidence slice
+    pub applications: Vec<usize>,
+    // Weakest link that merged this cluster, 1.0 for a single application
+    pub confidence: f32,
+}
+
+#[derive(Debug)]
+pub enum Ambiguity {
+    // Matched several applicants equally well, merged into the first one
+    SeveralCandidates {
+        application: usize,
+        applicants: Vec<usize>,
+        confidence: f32,
+    },
+    // Same name as `candidate`, but nothing to confirm or rule out the same person.
+    // The application went to `applicant`: a better match, or a cluster of its own.
+    NameOnly {
+        application: usize,
+        applicant: usize,
+        candidate: usize,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct Resolution {
+    pub applicants: Vec<ResolvedApplicant>,
+    pub ambiguities: Vec<Ambiguity>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Match {
+    Same(f32),
+    Different,
+    Unknown,
+}
+
+// Application scraping runs it before inserting `scraped.applicant` (not built yet).
+// The legacy import doesn't: `edbo_core` already assigned applicant ids.
+pub struct IdentityResolver;
+
+impl IdentityResolver {
+    pub fn resolve(evidence: &[ApplicationEvidence]) -> Resolution {
+        let mut resolution = Resolution::default();
+
+        for (index, application) in evidence.iter().enumerate() {
+            let name = Self::normalize_name(&application.name);
+
+            let mut best: Vec<usize> = Vec::new();
+            let mut best_confidence = 0.0_f32;
+            let mut name_only: Vec<usize> = Vec::new();
+
+            for (candidate, applicant) in resolution.applicants.iter().enumerate() {
+                if Self::normalize_name(&applicant.name) != name {
+                    continue;
+                }
+
+                match Self::compare(application, applicant, evidence) {
+                    Match::Same(confidence) if confidence > best_confidence => {
+                        best = vec![candidate];
+                        best_confidence = confidence;
+                    },
+                    Match::Same(confidence)
+                        if (confidence - best_confidence).abs() < f32::EPSILON =>
+                    {
+                        best.push(candidate);
+                    },
+                    Match::Same(_) | Match::Different => {},
+                    Match::Unknown => name_only.push(candidate),
+                }
+            }
+
+            let target = if let Some(&target) = best.first() {
+                if best.len() > 1 {
+                    resolution.ambiguities.push(Ambiguity::SeveralCandidates {
+                        application: index,
+                        applicants: best.clone(),
+                        confidence: best_confidence,
+                    });
+                }
+
+                if let Some(applicant) = resolution.applicants.get_mut(target) {
+                    applicant.applications.push(index);
+                    applicant.edbo_id = applicant.edbo_id.or(application.edbo_id);
+                    applicant.confidence = applicant.confidence.min(best_confidence);
+                }
+                target
+            } else {
+                resolution.applicants.push(ResolvedApplicant {
+                    name: application.name.clone(),
+                    edbo_id: application.edbo_id,
+                    applications: vec![index],
+                    confidence: 1.0,
+                });
+                resolution.applicants.len() - 1
+            };
+
+            // Reported whether or not a better match won: it may still be the same person
+            resolution
+                .ambiguities
+                .extend(name_only.into_iter().map(|candidate| Ambiguity::NameOnly {
+                    application: index,
+                    applicant: target,
+                    candidate,
+                }));
+        }
+
+        resolution
+    }
+
+    fn compare(
+        application: &ApplicationEvidence, applicant: &ResolvedApplicant,
+        evidence: &[ApplicationEvidence],
+    ) -> Match {
+        match (application.edbo_id, applicant.edbo_id) {
+            (Some(left), Some(right)) if left == right => return Match::Same(1.0),
+            (Some(_), Some(_)) => return Match::Different,
+            _ => {},
+        }
+
+        let known: Vec<&BigDecimal> = applicant
+            .applications
+            .iter()
+            .filter_map(|index| evidence.get(*index))
+            .flat_map(|other| other.grade_components.iter())
+            .map(GradeComponent::score)
+            .collect();
+
+        let own: Vec<&BigDecimal> = application
+            .grade_components
+            .iter()
+            .map(GradeComponent::score)
+            .collect();
+
+        let comparable = own.len().min(known.len());
+        if comparable == 0 {
+            return Match::Unknown;
+        }
+
+        let matches = own.iter().filter(|score| known.contains(score)).count();
+        let confidence = Self::ratio(matches.min(comparable), comparable);
+
+        if matches == 0 {
+            Match::Different
+        } else if confidence >= SAME_PERSON_THRESHOLD {
+            Match::Same(confidence)
+        } else {
+            Match::Unknown
+        }
+    }
+
+    fn normalize_name(name: &str) -> String {
+        name.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    fn ratio(part: usize, whole: usize) -> f32 {
+        let part = u16::try_from(part).unwrap_or(u16::MAX);
+        let whole = u16::try_from(whole).unwrap_or(u16::MAX);
+        f32::from(part) / f32::from(whole)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::applicant::errors::GradeComponentError;
+
+    fn evidence(
+        name: &str, components: &[&str],
+    ) -> Result<ApplicationEvidence, GradeComponentError> {
+        Ok(ApplicationEvidence {
+            name: name.to_string(),
+            edbo_id: None,
+            grade_components: components
+                .iter()
+                .map(|formula| GradeComponent::from_edbo("0", formula))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    #[test]
+    fn merges_same_scores_and_splits_namesakes() -> Result<(), GradeComponentError> {
+        let resolution = IdentityResolver::resolve(&[
+            evidence("Шевченко Т. Г.", &["180 x 0.5", "150 x 0.5"])?,
+            evidence("Шевченко  Т. Г.", &["180 x 0.4", "170 x 0.6"])?,
+            evidence("Шевченко Т. Г.", &["130 x 0.5", "120 x 0.5"])?,
+        ]);
+
+        let clusters: Vec<&Vec<usize>> = resolution
+            .applicants
+            .iter()
+            .map(|applicant| &applicant.applications)
+            .collect();
+        assert_eq!(clusters, [&vec![0, 1], &vec![2]]);
+        assert!(resolution.ambiguities.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn edbo_id_decides_over_scores() -> Result<(), GradeComponentError> {
+        let mut first = evidence("Франко І. Я.", &["180 x 0.5"])?;
+        first.edbo_id = Some(1);
+        let mut second = evidence("Франко І. Я.", &["180 x 0.5"])?;
+        second.edbo_id = Some(2);
+
+        let resolution = IdentityResolver::resolve(&[first, second]);
+
+        assert_eq!(resolution.applicants.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_name_only_matches() -> Result<(), GradeComponentError> {
+        let resolution = IdentityResolver::resolve(&[
+            evidence("Українка Л.", &[])?,
+            evidence("Українка Л.", &["160 x 1"])?,
+        ]);
+
+        assert_eq!(resolution.applicants.len(), 2);
+        assert!(matches!(
+            resolution.ambiguities.as_slice(),
+            [Ambiguity::NameOnly {
+                application: 1,
+                applicant: 1,
+                candidate: 0,
+            }]
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_name_only_matches_next_to_a_better_one() -> Result<(), GradeComponentError>
+    {
+        let resolution = IdentityResolver::resolve(&[
+            evidence("Стус В. С.", &["170 x 0.5", "140 x 0.5"])?,
+            evidence("Стус В. С.", &[])?,
+            evidence("Стус В. С.", &["170 x 0.3", "140 x 0.7"])?,
+        ]);
+
+        let clusters: Vec<&Vec<usize>> = resolution
+            .applicants
+            .iter()
+            .map(|applicant| &applicant.applications)
+            .collect();
+        assert_eq!(clusters, [&vec![0, 2], &vec![1]]);
+        assert!(matches!(
+            resolution.ambiguities.as_slice(),
+            [
+                Ambiguity::NameOnly {
+                    application: 1,
+                    applicant: 1,
+                    candidate: 0,
+                },
+                Ambiguity::NameOnly {
+                    application: 2,
+                    applicant: 0,
+                    candidate: 1,
+                },
+            ]
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod errors;
+pub mod priority;
+pub mod score;
+pub mod status;
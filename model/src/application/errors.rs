@@ -0,0 +1,17 @@
+use crate::application::status::ApplicationStatus;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PriorityError {
+    #[error("Unknown priority value: \"{0}\"")]
+    UnknownValue(String),
+
+    #[error("Unknown priority code: {0}")]
+    UnknownCode(i16),
+}
+
+#[derive(Debug, Error)]
+pub enum ApplicationStatusError {
+    #[error("Application status can't change from \"{0}\" to \"{1}\".")]
+    ImpossibleTransition(ApplicationStatus, ApplicationStatus),
+}
@@ -0,0 +1,154 @@
+use crate::application::errors::PriorityError;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Budget-funded applications a master's applicant may rank. Priorities beyond it are
+// either a parsing error or an application EDBO wouldn't consider for budget places.
+pub const MAX_BUDGET_PRIORITY: u8 = 5;
+
+// EDBO shows priorities as "4 (Б)" (budget, ranked) and "(К)" (contract, unranked)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Priority {
+    Budget(u8),
+    Contract,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PriorityViolation {
+    // The same budget priority on several applications
+    Duplicate(u8),
+    // Zero or above `MAX_BUDGET_PRIORITY`
+    OutOfRange(u8),
+    // Lower than the highest one used, but not used itself
+    Missing(u8),
+}
+
+impl Priority {
+    // Checks one applicant's priorities across all of their applications
+    pub fn validate(priorities: &[Self]) -> Vec<PriorityViolation> {
+        let mut counts: BTreeMap<u8, usize> = BTreeMap::new();
+        for priority in priorities {
+            if let Self::Budget(number) = priority {
+                *counts.entry(*number).or_default() += 1;
+            }
+        }
+
+        let mut violations = Vec::new();
+        for (number, count) in &counts {
+            if !(1..=MAX_BUDGET_PRIORITY).contains(number) {
+                violations.push(PriorityViolation::OutOfRange(*number));
+            }
+            if *count > 1 {
+                violations.push(PriorityViolation::Duplicate(*number));
+            }
+        }
+
+        let highest = counts
+            .keys()
+            .copied()
+            .filter(|number| *number <= MAX_BUDGET_PRIORITY)
+            .max()
+            .unwrap_or(0);
+        violations.extend(
+            (1..highest)
+                .filter(|number| !counts.contains_key(number))
+                .map(PriorityViolation::Missing),
+        );
+
+        violations
+    }
+}
+
+impl FromStr for Priority {
+    type Err = PriorityError;
+
+    // Tolerates missing spaces and Latin look-alikes ("4(B)", "(K)"), which show up once
+    // the value comes from rendered HTML instead of the old encrypted field.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized: String = value
+            .chars()
+            .filter(|char| !char.is_whitespace())
+            .map(|char| match char.to_ascii_uppercase() {
+                'B' | 'б' => 'Б',
+                'K' | 'к' => 'К',
+                char => char,
+            })
+            .collect();
+
+        if normalized == "(К)" {
+            return Ok(Self::Contract);
+        }
+
+        normalized
+            .strip_suffix("(Б)")
+            .and_then(|number| number.parse::<u8>().ok())
+            .map(Self::Budget)
+            .ok_or_else(|| PriorityError::UnknownValue(value.to_string()))
+    }
+}
+
+// Stored in `scraped.application.priority_code`, 0 for contract
+impl TryFrom<i16> for Priority {
+    type Error = PriorityError;
+
+    fn try_from(code: i16) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(Self::Contract),
+            code => u8::try_from(code)
+                .map(Self::Budget)
+                .map_err(|_| PriorityError::UnknownCode(code)),
+        }
+    }
+}
+
+impl From<Priority> for i16 {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Budget(number) => Self::from(number),
+            Priority::Contract => 0,
+        }
+    }
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Budget(number) => write!(f, "{number} (Б)"),
+            Self::Contract => write!(f, "(К)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rendered_priorities() -> Result<(), PriorityError> {
+        assert_eq!("4 (Б)".parse::<Priority>()?, Priority::Budget(4));
+        assert_eq!("4(B)".parse::<Priority>()?, Priority::Budget(4));
+        assert_eq!(" (К) ".parse::<Priority>()?, Priority::Contract);
+        assert!("(Б)".parse::<Priority>().is_err());
+        assert!("4".parse::<Priority>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reports_priority_violations() {
+        use Priority::{Budget, Contract};
+
+        assert!(
+            Priority::validate(&[Budget(2), Contract, Budget(1), Contract]).is_empty()
+        );
+        assert_eq!(
+            Priority::validate(&[Budget(1), Budget(1), Budget(4), Budget(9)]),
+            [
+                PriorityViolation::Duplicate(1),
+                PriorityViolation::OutOfRange(9),
+                PriorityViolation::Missing(2),
+                PriorityViolation::Missing(3),
+            ]
+        );
+    }
+}
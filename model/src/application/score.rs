@@ -0,0 +1,216 @@
+use crate::applicant::grade::GradeComponent;
+use crate::campaign::Campaign;
+use crate::speciality::Speciality;
+use bigdecimal::{BigDecimal, Zero};
+
+// Recomputes a competitive score ("конкурсний бал", EDBO's kv) from its components and
+// compares it with the published one. A mismatch means either a component was parsed
+// wrong or EDBO applied a coefficient that `ScoreFormula` doesn't know about.
+
+// EDBO publishes kv with three decimals; components are rounded separately before
+// summing, so exact equality is too strict.
+const DEFAULT_TOLERANCE_THOUSANDTHS: i64 = 10;
+
+// How a speciality turns weighted components into the competitive score. The per-test
+// weights are already part of each component's formula ("134 x 0.2").
+#[derive(Debug, Clone, Default)]
+pub struct ScoreFormula {
+    // Applied to the sum of components, in order
+    pub multipliers: Vec<ScoreMultiplier>,
+    // Upper bound of the score after multipliers, if the speciality has one
+    pub maximum: Option<BigDecimal>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoreMultiplier {
+    pub kind: MultiplierKind,
+    pub value: BigDecimal,
+}
+
+// EDBO doesn't publish multipliers next to the score, they come from the rules
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MultiplierKind {
+    Regional,
+    Sectoral,
+    Other,
+}
+
+impl ScoreFormula {
+    // What the campaign's rules apply to a master's score in this speciality
+    pub fn for_speciality(speciality: Speciality, campaign: Campaign) -> Self {
+        let multipliers = Self::multipliers(campaign)
+            .iter()
+            .filter(|(listed, ..)| *listed == speciality)
+            .map(|&(_, kind, thousandths)| ScoreMultiplier {
+                kind,
+                value: BigDecimal::new(thousandths.into(), 3),
+            })
+            .collect();
+
+        Self {
+            multipliers,
+            maximum: None,
+        }
+    }
+
+    // In thousandths, 1020 = x1.02. Empty until one is confirmed for master's: the
+    // regional and sectoral coefficients in the 2025 rules are the bachelor's ones. A
+    // steady stream of `Total` mismatches in one speciality is what earns it a row here.
+    const fn multipliers(
+        campaign: Campaign,
+    ) -> &'static [(Speciality, MultiplierKind, i64)] {
+        match campaign {
+            Campaign::Year2025 | Campaign::Year2026 => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScoreDiscrepancy {
+    // A component's "raw x coefficient" doesn't give its own contribution
+    Component {
+        index: usize,
+        expected: BigDecimal,
+        published: BigDecimal,
+    },
+    // Components add up to something other than the published kv
+    Total {
+        expected: BigDecimal,
+        published: BigDecimal,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoreVerifier {
+    pub formula: ScoreFormula,
+    pub tolerance: BigDecimal,
+}
+
+impl ScoreVerifier {
+    pub fn new(formula: ScoreFormula) -> Self {
+        Self {
+            formula,
+            tolerance: BigDecimal::new(DEFAULT_TOLERANCE_THOUSANDTHS.into(), 3),
+        }
+    }
+
+    pub fn for_speciality(speciality: Speciality, campaign: Campaign) -> Self {
+        Self::new(ScoreFormula::for_speciality(speciality, campaign))
+    }
+
+    pub fn recompute(&self, components: &[GradeComponent]) -> BigDecimal {
+        let sum = components
+            .iter()
+            .map(Self::weighted)
+            .fold(BigDecimal::zero(), |sum, value| sum + value);
+
+        let score = self
+            .formula
+            .multipliers
+            .iter()
+            .fold(sum, |score, multiplier| score * &multiplier.value);
+
+        match &self.formula.maximum {
+            Some(maximum) if score > *maximum => maximum.clone(),
+            _ => score,
+        }
+    }
+
+    pub fn verify(
+        &self, components: &[GradeComponent], published: &BigDecimal,
+    ) -> Vec<ScoreDiscrepancy> {
+        let mut discrepancies: Vec<ScoreDiscrepancy> = components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| {
+                !self
+                    .within_tolerance(&Self::weighted(component), &component.contribution)
+            })
+            .map(|(index, component)| ScoreDiscrepancy::Component {
+                index,
+                expected: Self::weighted(component),
+                published: component.contribution.clone(),
+            })
+            .collect();
+
+        let expected = self.recompute(components);
+        if !self.within_tolerance(&expected, published) {
+            discrepancies.push(ScoreDiscrepancy::Total {
+                expected,
+                published: published.clone(),
+            });
+        }
+
+        discrepancies
+    }
+
+    // Contribution as the formula says it should be; the published one when there's no
+    // formula (bonuses, legacy data)
+    fn weighted(component: &GradeComponent) -> BigDecimal {
+        component.formula.as_ref().map_or_else(
+            || component.contribution.clone(),
+            |formula| &formula.raw_score * &formula.coefficient,
+        )
+    }
+
+    fn within_tolerance(&self, left: &BigDecimal, right: &BigDecimal) -> bool {
+        (left - right).abs() <= self.tolerance
+    }
+}
+
+impl Default for ScoreVerifier {
+    fn default() -> Self {
+        Self::new(ScoreFormula::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::applicant::errors::GradeComponentError;
+
+    #[test]
+    fn flags_component_and_total_mismatches() -> Result<(), GradeComponentError> {
+        let components = [
+            GradeComponent::from_edbo("+26.800", "134 x 0.2")?,
+            GradeComponent::from_edbo("+120.000", "150 x 0.8")?,
+        ];
+        let verifier = ScoreVerifier::default();
+
+        assert_eq!(
+            verifier.recompute(&components),
+            BigDecimal::new(1468.into(), 1)
+        );
+        assert!(
+            verifier
+                .verify(&components, &BigDecimal::new(146_800.into(), 3))
+                .is_empty()
+        );
+
+        let sectoral = ScoreVerifier::new(ScoreFormula {
+            multipliers: vec![ScoreMultiplier {
+                kind: MultiplierKind::Sectoral,
+                value: BigDecimal::new(102.into(), 2),
+            }],
+            maximum: Some(BigDecimal::from(200)),
+        });
+        assert!(matches!(
+            sectoral
+                .verify(&components, &BigDecimal::new(1468.into(), 1))
+                .as_slice(),
+            [ScoreDiscrepancy::Total { .. }]
+        ));
+
+        let misparsed = [GradeComponent::from_edbo("+26.800", "143 x 0.2")?];
+        assert!(matches!(
+            verifier
+                .verify(&misparsed, &BigDecimal::new(268.into(), 1))
+                .as_slice(),
+            [
+                ScoreDiscrepancy::Component { index: 0, .. },
+                ScoreDiscrepancy::Total { .. }
+            ]
+        ));
+        Ok(())
+    }
+}
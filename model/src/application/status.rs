@@ -0,0 +1,235 @@
+use crate::application::errors::ApplicationStatusError;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use strum_macros::{Display, EnumIter, EnumString};
+
+// Discriminants are EDBO's `prsid` codes, stored in `common.application_status`
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    EnumIter,
+    EnumString,
+    Display,
+)]
+#[repr(i16)]
+pub enum ApplicationStatus {
+    #[strum(serialize = "Заява надійшла з сайту")]
+    ApplicationReceived = 1,
+    #[strum(serialize = "Затримано")]
+    Pending = 2,
+    #[strum(serialize = "Скасовано вступником")]
+    CancelledByApplicant = 3,
+    #[strum(serialize = "Скасовано (втрата пріоритету)")]
+    CancelledPriorityLost = 4,
+    #[strum(serialize = "Зареєстровано")]
+    Registered = 5,
+    #[strum(serialize = "Допущено")]
+    Admitted = 6,
+    #[strum(serialize = "Відмова")]
+    Rejected = 7,
+    #[strum(serialize = "Скасовано закладом освіти")]
+    CancelledByInstitution = 8,
+    #[strum(serialize = "Рекомендовано (бюджет)")]
+    RecommendedBudget = 9,
+    #[strum(serialize = "Відхилено (бюджет)")]
+    RejectedBudget = 10,
+    #[strum(serialize = "Допущено (контракт, за ріш. ПК)")]
+    AdmittedContractDecision = 11,
+    #[strum(serialize = "Рекомендовано (контракт)")]
+    RecommendedContract = 12,
+    #[strum(serialize = "Відхилено (контракт)")]
+    RejectedContract = 13,
+    #[strum(serialize = "До наказу")]
+    ToEnrollmentOrder = 14,
+    #[strum(serialize = "Відраховано")]
+    Expelled = 15,
+    #[strum(serialize = "Деактивовано (зараховано на навчання)")]
+    DeactivatedEnrolled = 16,
+}
+
+impl ApplicationStatus {
+    // Statuses EDBO can move an application to from this one. Every cancellation and
+    // final outcome is a dead end; "Відхилено (бюджет)" isn't, since the application
+    // still competes for contract places and for budget places freed later.
+    pub const fn next_statuses(self) -> &'static [Self] {
+        use ApplicationStatus::{
+            Admitted, AdmittedContractDecision, ApplicationReceived,
+            CancelledByApplicant, CancelledByInstitution, CancelledPriorityLost,
+            DeactivatedEnrolled, Expelled, Pending, RecommendedBudget,
+            RecommendedContract, Registered, Rejected, RejectedBudget, RejectedContract,
+            ToEnrollmentOrder,
+        };
+
+        match self {
+            ApplicationReceived => &[
+                Pending,
+                Registered,
+                Rejected,
+                CancelledByApplicant,
+                CancelledByInstitution,
+            ],
+            Pending => &[
+                ApplicationReceived,
+                Registered,
+                Rejected,
+                CancelledByApplicant,
+                CancelledByInstitution,
+            ],
+            Registered => &[
+                Pending,
+                Admitted,
+                Rejected,
+                CancelledByApplicant,
+                CancelledByInstitution,
+            ],
+            Admitted => &[
+                RecommendedBudget,
+                RejectedBudget,
+                AdmittedContractDecision,
+                RecommendedContract,
+                CancelledByApplicant,
+                CancelledPriorityLost,
+                CancelledByInstitution,
+            ],
+            RecommendedBudget => &[
+                ToEnrollmentOrder,
+                RejectedBudget,
+                CancelledByApplicant,
+                CancelledPriorityLost,
+                CancelledByInstitution,
+            ],
+            RejectedBudget => &[
+                RecommendedBudget,
+                AdmittedContractDecision,
+                RecommendedContract,
+                RejectedContract,
+                CancelledByApplicant,
+                CancelledByInstitution,
+            ],
+            AdmittedContractDecision => &[
+                RecommendedContract,
+                RejectedContract,
+                CancelledByApplicant,
+                CancelledPriorityLost,
+                CancelledByInstitution,
+            ],
+            RecommendedContract => &[
+                ToEnrollmentOrder,
+                RejectedContract,
+                CancelledByApplicant,
+                CancelledPriorityLost,
+                CancelledByInstitution,
+            ],
+            ToEnrollmentOrder => &[Expelled, DeactivatedEnrolled, CancelledByInstitution],
+            CancelledByApplicant
+            | CancelledPriorityLost
+            | CancelledByInstitution
+            | Rejected
+            | RejectedContract
+            | Expelled
+            | DeactivatedEnrolled => &[],
+        }
+    }
+
+    pub fn validate_transition(self, next: Self) -> Result<(), ApplicationStatusError> {
+        if self == next || self.next_statuses().contains(&next) {
+            Ok(())
+        } else {
+            Err(ApplicationStatusError::ImpossibleTransition(self, next))
+        }
+    }
+
+    pub const fn is_terminal(self) -> bool {
+        self.next_statuses().is_empty()
+    }
+
+    pub const fn is_active(self) -> bool {
+        !self.is_terminal()
+    }
+
+    pub const fn is_cancelled(self) -> bool {
+        matches!(
+            self,
+            Self::CancelledByApplicant
+                | Self::CancelledPriorityLost
+                | Self::CancelledByInstitution
+        )
+    }
+
+    pub const fn is_recommended(self) -> bool {
+        matches!(self, Self::RecommendedBudget | Self::RecommendedContract)
+    }
+
+    // Applications placement ranks for budget places: every status that can still be
+    // recommended for one, and the ones already on their way to enrolment. Ones not
+    // admitted yet or held are included, since a forecast runs before admission
+    // decisions are made, and "Відхилено (бюджет)" since places freed later go to them.
+    pub const fn competes_for_budget(self) -> bool {
+        matches!(
+            self,
+            Self::ApplicationReceived
+                | Self::Pending
+                | Self::Registered
+                | Self::Admitted
+                | Self::RecommendedBudget
+                | Self::RejectedBudget
+                | Self::ToEnrollmentOrder
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn dead_ends_stay_dead() {
+        for status in ApplicationStatus::iter().filter(|status| status.is_cancelled()) {
+            assert!(status.is_terminal());
+            assert!(
+                status
+                    .validate_transition(ApplicationStatus::Admitted)
+                    .is_err()
+            );
+        }
+
+        for status in ApplicationStatus::iter() {
+            for next in status.next_statuses() {
+                assert_ne!(status, *next);
+            }
+        }
+
+        assert!(
+            ApplicationStatus::Admitted
+                .validate_transition(ApplicationStatus::RecommendedBudget)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn budget_competition_is_whatever_can_still_be_recommended() {
+        use ApplicationStatus::{RecommendedBudget, ToEnrollmentOrder};
+
+        for status in ApplicationStatus::iter() {
+            let mut reachable = vec![status];
+            let mut index = 0;
+            while let Some(current) = reachable.get(index).copied() {
+                for next in current.next_statuses() {
+                    if !reachable.contains(next) {
+                        reachable.push(*next);
+                    }
+                }
+                index += 1;
+            }
+
+            let competes =
+                reachable.contains(&RecommendedBudget) || status == ToEnrollmentOrder;
+            assert_eq!(status.competes_for_budget(), competes, "{status:?}");
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use strum_macros::{Display, EnumString};
+
+// Admission year. Offers, applicants and applications are scoped by it,
+// since EDBO reuses their ids from year to year.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    EnumString,
+    Display,
+)]
+#[repr(i16)]
+pub enum Campaign {
+    #[strum(serialize = "2025")]
+    Year2025 = 2025,
+    #[strum(serialize = "2026")]
+    Year2026 = 2026,
+}
+
+impl Campaign {
+    pub const CURRENT: Self = Self::Year2026;
+}
@@ -1,7 +1,20 @@
+use crate::campaign::Campaign;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use strum_macros::{Display, EnumString};
 
-#[derive(Debug, Copy, Clone, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
+// Discriminants are our stable ids (stored in `common.institution_category`), not EDBO's
+// codes. They were taken from the 2025 API, EDBO's `ut` codes per year are mapped below.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    EnumString,
+    Display,
+)]
 #[repr(i16)]
 pub enum InstitutionCategory {
     #[strum(serialize = "Заклад вищої освіти")]
@@ -24,3 +37,72 @@ pub enum InstitutionCategory {
     #[strum(serialize = "")]
     Unknown = 5,
 }
+
+impl InstitutionCategory {
+    pub fn edbo_code(self, campaign: Campaign) -> Option<i16> {
+        Self::edbo_codes(campaign)
+            .iter()
+            .find(|(category, _)| *category == self)
+            .map(|(_, code)| *code)
+    }
+
+    pub fn from_edbo_code(code: i16, campaign: Campaign) -> Option<Self> {
+        Self::edbo_codes(campaign)
+            .iter()
+            .find(|(_, edbo_code)| *edbo_code == code)
+            .map(|(category, _)| *category)
+    }
+
+    // 2026 lists only codes confirmed against the live registry. An unlisted category
+    // is reported as unmapped instead of silently reusing its 2025 code.
+    const fn edbo_codes(campaign: Campaign) -> &'static [(Self, i16)] {
+        match campaign {
+            Campaign::Year2025 => &[
+                (Self::HigherEducation, 1),
+                (Self::ProfessionalCollege, 9),
+                (Self::VocationalEducation, 2),
+                (Self::SecondaryEducation, 3),
+                (Self::ScientificInstitutes, 8),
+                (Self::Postgrad, 10),
+                (Self::OtherVET, 4),
+                (Self::Unknown, 5),
+            ],
+            Campaign::Year2026 => &[(Self::HigherEducation, 1)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both directions agree for every listed pair, so no code or variant is listed twice
+    #[test]
+    fn edbo_codes_round_trip_per_campaign() {
+        for campaign in [Campaign::Year2025, Campaign::Year2026] {
+            for &(variant, code) in InstitutionCategory::edbo_codes(campaign) {
+                assert_eq!(variant.edbo_code(campaign), Some(code), "{campaign}");
+                assert_eq!(
+                    InstitutionCategory::from_edbo_code(code, campaign),
+                    Some(variant)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unconfirmed_2026_codes_stay_unmapped() {
+        assert_eq!(
+            InstitutionCategory::ProfessionalCollege.edbo_code(Campaign::Year2025),
+            Some(9)
+        );
+        assert_eq!(
+            InstitutionCategory::ProfessionalCollege.edbo_code(Campaign::Year2026),
+            None
+        );
+        assert_eq!(
+            InstitutionCategory::from_edbo_code(9, Campaign::Year2026),
+            None
+        );
+    }
+}
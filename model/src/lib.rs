@@ -1,3 +1,7 @@
+pub mod applicant;
+pub mod application;
+pub mod campaign;
 pub mod institution;
 pub mod region;
 pub mod schemas;
+pub mod speciality;
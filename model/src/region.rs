@@ -1,7 +1,20 @@
+use crate::campaign::Campaign;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use strum_macros::{Display, EnumString};
 
-#[derive(Debug, Copy, Clone, IntoPrimitive, TryFromPrimitive, EnumString, Display)]
+// Discriminants are our stable ids (stored in `common.region`), not EDBO's codes.
+// They were taken from the 2025 API, EDBO's `rg` codes per year are mapped below.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    EnumString,
+    Display,
+)]
 #[repr(i16)]
 pub enum Region {
     Every = 0,
@@ -57,3 +70,78 @@ pub enum Region {
     #[strum(serialize = "Чернігівська")]
     Chernihiv = 74,
 }
+
+impl Region {
+    pub fn edbo_code(self, campaign: Campaign) -> Option<i16> {
+        Self::edbo_codes(campaign)
+            .iter()
+            .find(|(region, _)| *region == self)
+            .map(|(_, code)| *code)
+    }
+
+    pub fn from_edbo_code(code: i16, campaign: Campaign) -> Option<Self> {
+        Self::edbo_codes(campaign)
+            .iter()
+            .find(|(_, edbo_code)| *edbo_code == code)
+            .map(|(region, _)| *region)
+    }
+
+    // 2026 lists only codes confirmed against the live registry. An unlisted region
+    // is reported as unmapped instead of silently reusing its 2025 code.
+    const fn edbo_codes(campaign: Campaign) -> &'static [(Self, i16)] {
+        match campaign {
+            Campaign::Year2025 => &[
+                (Self::Every, 0),
+                (Self::KyivCity, 80),
+                (Self::Vinnytsia, 5),
+                (Self::Volyn, 7),
+                (Self::Dnipropetrovsk, 12),
+                (Self::Donetsk, 14),
+                (Self::Zhytomyr, 18),
+                (Self::Zakarpattia, 21),
+                (Self::Zaporizhzhia, 23),
+                (Self::IvanoFrankivsk, 26),
+                (Self::Kyiv, 32),
+                (Self::Kirovohrad, 35),
+                (Self::Luhansk, 44),
+                (Self::Lviv, 46),
+                (Self::Mykolaiv, 48),
+                (Self::Odesa, 51),
+                (Self::Poltava, 53),
+                (Self::Rivne, 56),
+                (Self::Sumy, 59),
+                (Self::Ternopil, 61),
+                (Self::Kharkiv, 63),
+                (Self::Kherson, 65),
+                (Self::Khmelnytskyi, 68),
+                (Self::Cherkasy, 71),
+                (Self::Chernivtsi, 73),
+                (Self::Chernihiv, 74),
+            ],
+            Campaign::Year2026 => &[(Self::Every, 0)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both directions agree for every listed pair, so no code or variant is listed twice
+    #[test]
+    fn edbo_codes_round_trip_per_campaign() {
+        for campaign in [Campaign::Year2025, Campaign::Year2026] {
+            for &(variant, code) in Region::edbo_codes(campaign) {
+                assert_eq!(variant.edbo_code(campaign), Some(code), "{campaign}");
+                assert_eq!(Region::from_edbo_code(code, campaign), Some(variant));
+            }
+        }
+    }
+
+    #[test]
+    fn unconfirmed_2026_codes_stay_unmapped() {
+        assert_eq!(Region::Lviv.edbo_code(Campaign::Year2025), Some(46));
+        assert_eq!(Region::Lviv.edbo_code(Campaign::Year2026), None);
+        assert_eq!(Region::from_edbo_code(46, Campaign::Year2026), None);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::speciality::knowledge_field::KnowledgeField;
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+
+pub mod knowledge_field;
+pub mod numeric;
+
+// Source: https://zakon.rada.gov.ua/laws/show/266-2015-п#n11
+// Generates `Speciality` with its code and knowledge field from one table,
+// so a code can't drift away from its title.
+macro_rules! define_specialities {
+    (
+        $(
+            $field:ident {
+                $( { code: $code:literal, ua: $ua:literal, $variant:ident } )*
+            }
+        )*
+    ) => {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, EnumString, Display)]
+        pub enum Speciality {
+            $(
+                $(
+                    #[strum(serialize = $ua)]
+                    $variant,
+                )*
+            )*
+        }
+
+        impl Speciality {
+            // Stored in `common.speciality.code`
+            pub const fn code(self) -> &'static str {
+                match self {
+                    $( $( Self::$variant => $code, )* )*
+                }
+            }
+
+            pub const fn knowledge_field(self) -> KnowledgeField {
+                match self {
+                    $( $( Self::$variant => KnowledgeField::$field, )* )*
+                }
+            }
+        }
+    };
+}
+
+impl Speciality {
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::iter().find(|speciality| speciality.code() == code)
+    }
+}
+
+define_specialities! {
+    Education {
+        { code: "A1", ua: "Освітні науки", EducationalSciences }
+        { code: "A2", ua: "Дошкільна освіта", PreSchoolEducation }
+        { code: "A3", ua: "Початкова освіта", PrimaryEducation }
+        { code: "A4", ua: "Середня освіта (за предметними спеціальностями)", SecondaryEducation }
+        { code: "A5", ua: "Професійна освіта (за спеціалізаціями)", ProfessionalEducation }
+        { code: "A6", ua: "Спеціальна освіта (за спеціалізаціями)", SpecialEducation }
+        { code: "A7", ua: "Фізична культура і спорт", PhysicalEducationSports }
+    }
+
+    CultureArtsHumanities {
+        { code: "B1", ua: "Аудіовізуальне мистецтво та медіавиробництво", MediaProduction }
+        { code: "B2", ua: "Дизайн", Design }
+        { code: "B3", ua: "Декоративне мистецтво та ремесла", DecorativeArtsCrafts }
+        { code: "B4", ua: "Образотворче мистецтво та реставрація", FineArtsRestoration }
+        { code: "B5", ua: "Музичне мистецтво", MusicArts }
+        { code: "B6", ua: "Перформативні мистецтва", PerformingArts }
+        { code: "B7", ua: "Релігієзнавство", ReligiousStudies }
+        { code: "B8", ua: "Богослов’я", Theology }
+        { code: "B9", ua: "Історія та археологія", HistoryArchaeology }
+        { code: "B10", ua: "Філософія", Philosophy }
+        { code: "B11", ua: "Філологія (за спеціалізаціями)", Philology }
+        { code: "B12", ua: "Культурологія та музеєзнавство", CulturalStudiesMuseology }
+        { code: "B13", ua: "Бібліотечна, інформаційна та архівна справа", LibraryArchivalStudies }
+        { code: "B14", ua: "Організація соціокультурної діяльності", SocioCulturalActivities }
+    }
+
+    SocialSciences {
+        { code: "C1", ua: "Економіка та міжнародні економічні відносини (за спеціалізаціями)", Economics }
+        { code: "C2", ua: "Політологія", PoliticalScience }
+        { code: "C3", ua: "Міжнародні відносини", InternationalRelations }
+        { code: "C4", ua: "Психологія", Psychology }
+        { code: "C5", ua: "Соціологія", Sociology }
+        { code: "C6", ua: "Географія та регіональні студії", Geography }
+        { code: "C7", ua: "Журналістика", Journalism }
+    }
+
+    BusinessAdministrationLaw {
+        { code: "D1", ua: "Облік і оподаткування", AccountingTaxation }
+        { code: "D2", ua: "Фінанси, банківська справа, страхування та фондовий ринок", FinanceBankingInsurance }
+        { code: "D3", ua: "Менеджмент", Management }
+        { code: "D4", ua: "Публічне управління та адміністрування", PublicAdministration }
+        { code: "D5", ua: "Маркетинг", Marketing }
+        { code: "D6", ua: "Секретарська та офісна справа", SecretarialWork }
+        { code: "D7", ua: "Торгівля", Trade }
+        { code: "D8", ua: "Право", Law }
+        { code: "D9", ua: "Міжнародне право", InternationalLaw }
+    }
+
+    NaturalSciencesMathematics {
+        { code: "E1", ua: "Біологія та біохімія", BiologyBiochemistry }
+        { code: "E2", ua: "Екологія", Ecology }
+        { code: "E3", ua: "Хімія", Chemistry }
+        { code: "E4", ua: "Науки про Землю", EarthSciences }
+        { code: "E5", ua: "Фізика та астрономія", PhysicsAstronomy }
+        { code: "E6", ua: "Прикладна фізика та наноматеріали", AppliedPhysics }
+        { code: "E7", ua: "Математика", Mathematics }
+        { code: "E8", ua: "Статистика", Statistics }
+    }
+
+    InformationTechnologies {
+        { code: "F1", ua: "Прикладна математика", AppliedMathematics }
+        { code: "F2", ua: "Інженерія програмного забезпечення", SoftwareEngineering }
+        { code: "F3", ua: "Комп’ютерні науки", ComputerScience }
+        { code: "F4", ua: "Системний аналіз та наука про дані", SystemAnalysisDataScience }
+        { code: "F5", ua: "Кібербезпека та захист інформації", Cybersecurity }
+        { code: "F6", ua: "Інформаційні системи і технології", InformationSystemsTechnologies }
+        { code: "F7", ua: "Комп’ютерна інженерія", ComputerEngineering }
+    }
+
+    EngineeringManufacturingConstruction {
+        { code: "G1", ua: "Хімічні технології та інженерія", ChemicalEngineering }
+        { code: "G2", ua: "Технології захисту навколишнього середовища", EnvironmentalProtection }
+        { code: "G3", ua: "Електрична інженерія", ElectricalEngineering }
+        { code: "G4", ua: "Енерговиробництво (за спеціалізацією)", PowerGeneration }
+        { code: "G5", ua: "Електроніка, електронні комунікації, приладобудування та радіотехніка", Electronics }
+        { code: "G6", ua: "Інформаційно-вимірювальні технології", MeasurementTechnologies }
+        { code: "G7", ua: "Автоматизація, комп’ютерно-інтегровані технології та робототехніка", AutomationRobotics }
+        { code: "G8", ua: "Матеріалознавство", MaterialsScience }
+        { code: "G9", ua: "Прикладна механіка", AppliedMechanics }
+        { code: "G10", ua: "Металургія", Metallurgy }
+        { code: "G11", ua: "Машинобудування (за спеціалізаціями)", MechanicalEngineering }
+        { code: "G12", ua: "Авіаційна та ракетно-космічна техніка", AerospaceEngineering }
+        { code: "G13", ua: "Харчові технології", FoodTechnologies }
+        { code: "G14", ua: "Деревообробні та меблеві технології", WoodworkingFurniture }
+        { code: "G15", ua: "Технології легкої промисловості", LightIndustry }
+        { code: "G16", ua: "Гірництво та нафтогазові технології", MiningOilGas }
+        { code: "G17", ua: "Архітектура та містобудування", ArchitectureTownPlanning }
+        { code: "G18", ua: "Геодезія та землеустрій", GeodesyLandManagement }
+        { code: "G19", ua: "Будівництво та цивільна інженерія", CivilEngineering }
+        { code: "G20", ua: "Видавництво та поліграфія", PublishingPrinting }
+        { code: "G21", ua: "Біотехнології та біоінженерія", BiotechnologyBioengineering }
+        { code: "G22", ua: "Біомедична інженерія", BiomedicalEngineering }
+    }
+
+    AgricultureForestryFisheriesVeterinary {
+        { code: "H1", ua: "Агрономія", Agronomy }
+        { code: "H2", ua: "Тваринництво", Livestock }
+        { code: "H3", ua: "Садово-паркове господарство", LandscapeGardening }
+        { code: "H4", ua: "Лісове господарство", Forestry }
+        { code: "H5", ua: "Водні біоресурси та аквакультура", Aquaculture }
+        { code: "H6", ua: "Ветеринарна медицина", VeterinaryMedicine }
+        { code: "H7", ua: "Агроінженерія", AgriculturalEngineering }
+    }
+
+    HealthcareSocialSecurity {
+        { code: "I1", ua: "Стоматологія", Dentistry }
+        { code: "I2", ua: "Медицина", Medicine }
+        { code: "I3", ua: "Педіатрія", Pediatrics }
+        { code: "I4", ua: "Медична психологія", MedicalPsychology }
+        { code: "I5", ua: "Медсестринство (за спеціалізаціями)", Nursing }
+        { code: "I6", ua: "Технології медичної діагностики та лікування (за спеціалізаціями)", MedicalDiagnostics }
+        { code: "I7", ua: "Терапія та реабілітація (за спеціалізаціями)", TherapyRehabilitation }
+        { code: "I8", ua: "Фармація (за спеціалізаціями)", Pharmacy }
+        { code: "I9", ua: "Громадське здоров’я", PublicHealth }
+        { code: "I10", ua: "Соціальна робота та консультування", SocialWork }
+        { code: "I11", ua: "Дитячі та молодіжні служби", ChildrenYouthServices }
+    }
+
+    TransportServices {
+        { code: "J1", ua: "Послуги краси", BeautyServices }
+        { code: "J2", ua: "Готельно-ресторанна справа та кейтеринг", HotelRestaurantCatering }
+        { code: "J3", ua: "Туризм та рекреація", TourismRecreation }
+        { code: "J4", ua: "Охорона праці", OccupationalSafety }
+        { code: "J5", ua: "Морський та внутрішній водний транспорт", WaterTransport }
+        { code: "J6", ua: "Авіаційний транспорт", AirTransport }
+        { code: "J7", ua: "Залізничний транспорт", RailwayTransport }
+        { code: "J8", ua: "Автомобільний транспорт", RoadTransport }
+    }
+
+    SecurityDefense {
+        { code: "K1", ua: "Державна безпека", StateSecurity }
+        { code: "K2", ua: "Безпека державного кордону", BorderSecurity }
+        { code: "K3", ua: "Національна безпека (за окремими сферами забезпечення і видами діяльності)", NationalSecurity }
+        { code: "K4", ua: "Управління інформаційною безпекою", InformationSecurityManagement }
+        { code: "K5", ua: "Військове управління (за видами збройних сил)", MilitaryAdministration }
+        { code: "K6", ua: "Забезпечення військ (сил)", TroopSupply }
+        { code: "K7", ua: "Озброєння та військова техніка", WeaponsMilitaryEquipment }
+        { code: "K8", ua: "Пожежна безпека", FireSafety }
+        { code: "K9", ua: "Правоохоронна діяльність", LawEnforcement }
+        { code: "K10", ua: "Цивільна безпека", CivilSecurity }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn codes_start_with_their_knowledge_field() {
+        for speciality in Speciality::iter() {
+            let number = speciality
+                .code()
+                .strip_prefix(speciality.knowledge_field().code())
+                .map(str::parse::<u8>);
+            assert!(matches!(number, Some(Ok(_))), "{speciality:?}");
+        }
+    }
+
+    #[test]
+    fn codes_are_unique_and_resolve_back() {
+        let codes: HashSet<_> = Speciality::iter().map(Speciality::code).collect();
+        assert_eq!(codes.len(), Speciality::iter().count());
+
+        for speciality in Speciality::iter() {
+            assert_eq!(Speciality::from_code(speciality.code()), Some(speciality));
+        }
+        for field in KnowledgeField::iter() {
+            assert_eq!(KnowledgeField::from_code(field.code()), Some(field));
+            assert!(field.specialities().next().is_some(), "{field:?}");
+        }
+    }
+}
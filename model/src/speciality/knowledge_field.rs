@@ -0,0 +1,58 @@
+use crate::speciality::Speciality;
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, EnumString, Display)]
+pub enum KnowledgeField {
+    #[strum(serialize = "Освіта")]
+    Education,
+    #[strum(serialize = "Культура, мистецтво та гуманітарні науки")]
+    CultureArtsHumanities,
+    #[strum(
+        serialize = "Соціальні науки, журналістика, інформація та міжнародні відносини"
+    )]
+    SocialSciences,
+    #[strum(serialize = "Бізнес, адміністрування та право")]
+    BusinessAdministrationLaw,
+    #[strum(serialize = "Природничі науки, математика та статистика")]
+    NaturalSciencesMathematics,
+    #[strum(serialize = "Інформаційні технології")]
+    InformationTechnologies,
+    #[strum(serialize = "Інженерія, виробництво та будівництво")]
+    EngineeringManufacturingConstruction,
+    #[strum(serialize = "Сільське, лісове, рибне господарство та ветеринарна медицина")]
+    AgricultureForestryFisheriesVeterinary,
+    #[strum(serialize = "Охорона здоров’я та соціальне забезпечення")]
+    HealthcareSocialSecurity,
+    #[strum(serialize = "Транспорт та послуги")]
+    TransportServices,
+    #[strum(serialize = "Безпека та оборона")]
+    SecurityDefense,
+}
+
+impl KnowledgeField {
+    // Stored in `common.knowledge_field.code`
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Education => "A",
+            Self::CultureArtsHumanities => "B",
+            Self::SocialSciences => "C",
+            Self::BusinessAdministrationLaw => "D",
+            Self::NaturalSciencesMathematics => "E",
+            Self::InformationTechnologies => "F",
+            Self::EngineeringManufacturingConstruction => "G",
+            Self::AgricultureForestryFisheriesVeterinary => "H",
+            Self::HealthcareSocialSecurity => "I",
+            Self::TransportServices => "J",
+            Self::SecurityDefense => "K",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::iter().find(|field| field.code() == code)
+    }
+
+    pub fn specialities(self) -> impl Iterator<Item = Speciality> {
+        Speciality::iter().filter(move |speciality| speciality.knowledge_field() == self)
+    }
+}
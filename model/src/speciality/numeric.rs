@@ -0,0 +1,167 @@
+use crate::speciality::Speciality;
+
+// Numeric codes used before the 2025 reform (e.g. "121"), for joining older data.
+// Several old specialities merged into one new one, so the mapping is many-to-one.
+// Old specialities that were split between new ones map to the successor that kept
+// most of their scope; ones without a clear successor are left out.
+const NUMERIC_CODES: &[(&str, Speciality)] = &[
+    // 01 Освіта/Педагогіка
+    ("011", Speciality::EducationalSciences),
+    ("012", Speciality::PreSchoolEducation),
+    ("013", Speciality::PrimaryEducation),
+    ("014", Speciality::SecondaryEducation),
+    ("015", Speciality::ProfessionalEducation),
+    ("016", Speciality::SpecialEducation),
+    ("017", Speciality::PhysicalEducationSports),
+    // 02 Культура і мистецтво
+    ("021", Speciality::MediaProduction),
+    ("022", Speciality::Design),
+    // Split: decorative arts went to B3
+    ("023", Speciality::FineArtsRestoration),
+    ("024", Speciality::PerformingArts),
+    ("025", Speciality::MusicArts),
+    ("026", Speciality::PerformingArts),
+    ("027", Speciality::CulturalStudiesMuseology),
+    ("028", Speciality::SocioCulturalActivities),
+    ("029", Speciality::LibraryArchivalStudies),
+    // 03 Гуманітарні науки, 04 Богослов'я
+    ("031", Speciality::ReligiousStudies),
+    ("032", Speciality::HistoryArchaeology),
+    ("033", Speciality::Philosophy),
+    ("034", Speciality::CulturalStudiesMuseology),
+    ("035", Speciality::Philology),
+    ("041", Speciality::Theology),
+    // 05 Соціальні та поведінкові науки, 06 Журналістика
+    ("051", Speciality::Economics),
+    ("052", Speciality::PoliticalScience),
+    ("053", Speciality::Psychology),
+    ("054", Speciality::Sociology),
+    ("061", Speciality::Journalism),
+    // 07 Управління та адміністрування, 08 Право
+    ("071", Speciality::AccountingTaxation),
+    ("072", Speciality::FinanceBankingInsurance),
+    ("073", Speciality::Management),
+    ("075", Speciality::Marketing),
+    ("076", Speciality::Trade),
+    ("081", Speciality::Law),
+    // 09 Біологія, 10 Природничі науки, 11 Математика та статистика
+    ("091", Speciality::BiologyBiochemistry),
+    ("101", Speciality::Ecology),
+    ("102", Speciality::Chemistry),
+    ("103", Speciality::EarthSciences),
+    ("104", Speciality::PhysicsAstronomy),
+    ("105", Speciality::AppliedPhysics),
+    ("106", Speciality::Geography),
+    ("111", Speciality::Mathematics),
+    ("112", Speciality::Statistics),
+    ("113", Speciality::AppliedMathematics),
+    // 12 Інформаційні технології
+    ("121", Speciality::SoftwareEngineering),
+    ("122", Speciality::ComputerScience),
+    ("123", Speciality::ComputerEngineering),
+    ("124", Speciality::SystemAnalysisDataScience),
+    ("125", Speciality::Cybersecurity),
+    ("126", Speciality::InformationSystemsTechnologies),
+    // 13 Механічна інженерія, 14 Електрична інженерія, 15 Автоматизація
+    ("131", Speciality::AppliedMechanics),
+    ("132", Speciality::MaterialsScience),
+    ("133", Speciality::MechanicalEngineering),
+    ("134", Speciality::AerospaceEngineering),
+    ("136", Speciality::Metallurgy),
+    ("141", Speciality::ElectricalEngineering),
+    ("142", Speciality::PowerGeneration),
+    ("143", Speciality::PowerGeneration),
+    ("144", Speciality::PowerGeneration),
+    ("145", Speciality::PowerGeneration),
+    ("151", Speciality::AutomationRobotics),
+    ("152", Speciality::MeasurementTechnologies),
+    // 16 Хімічна та біоінженерія, 17 Електроніка, 18 Виробництво та технології
+    ("161", Speciality::ChemicalEngineering),
+    ("162", Speciality::BiotechnologyBioengineering),
+    ("163", Speciality::BiomedicalEngineering),
+    ("171", Speciality::Electronics),
+    ("172", Speciality::Electronics),
+    ("181", Speciality::FoodTechnologies),
+    ("182", Speciality::LightIndustry),
+    ("183", Speciality::EnvironmentalProtection),
+    ("184", Speciality::MiningOilGas),
+    ("185", Speciality::MiningOilGas),
+    ("186", Speciality::PublishingPrinting),
+    ("187", Speciality::WoodworkingFurniture),
+    // 19 Архітектура та будівництво
+    ("191", Speciality::ArchitectureTownPlanning),
+    ("192", Speciality::CivilEngineering),
+    ("193", Speciality::GeodesyLandManagement),
+    // 20 Аграрні науки, 21 Ветеринарна медицина
+    ("201", Speciality::Agronomy),
+    ("204", Speciality::Livestock),
+    ("205", Speciality::Forestry),
+    ("206", Speciality::LandscapeGardening),
+    ("207", Speciality::Aquaculture),
+    ("208", Speciality::AgriculturalEngineering),
+    ("211", Speciality::VeterinaryMedicine),
+    ("212", Speciality::VeterinaryMedicine),
+    // 22 Охорона здоров'я, 23 Соціальна робота
+    ("221", Speciality::Dentistry),
+    ("222", Speciality::Medicine),
+    ("223", Speciality::Nursing),
+    ("224", Speciality::MedicalDiagnostics),
+    ("225", Speciality::MedicalPsychology),
+    ("226", Speciality::Pharmacy),
+    ("227", Speciality::TherapyRehabilitation),
+    ("228", Speciality::Pediatrics),
+    ("229", Speciality::PublicHealth),
+    ("231", Speciality::SocialWork),
+    ("232", Speciality::SocialWork),
+    // 24 Сфера обслуговування
+    ("241", Speciality::HotelRestaurantCatering),
+    ("242", Speciality::TourismRecreation),
+    // 25 Воєнні науки, 26 Цивільна безпека
+    ("251", Speciality::StateSecurity),
+    ("252", Speciality::BorderSecurity),
+    ("253", Speciality::MilitaryAdministration),
+    ("254", Speciality::TroopSupply),
+    ("255", Speciality::WeaponsMilitaryEquipment),
+    ("256", Speciality::NationalSecurity),
+    ("261", Speciality::FireSafety),
+    ("262", Speciality::LawEnforcement),
+    // Split: occupational safety went to J4
+    ("263", Speciality::CivilSecurity),
+    // 27 Транспорт
+    ("271", Speciality::WaterTransport),
+    ("272", Speciality::AirTransport),
+    ("273", Speciality::RailwayTransport),
+    ("274", Speciality::RoadTransport),
+    // 28 Публічне управління, 29 Міжнародні відносини
+    ("281", Speciality::PublicAdministration),
+    ("291", Speciality::InternationalRelations),
+    ("292", Speciality::Economics),
+    ("293", Speciality::InternationalLaw),
+];
+
+impl Speciality {
+    pub fn from_numeric_code(code: &str) -> Option<Self> {
+        NUMERIC_CODES
+            .iter()
+            .find(|(numeric, _)| *numeric == code)
+            .map(|(_, speciality)| *speciality)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn numeric_codes_are_unique_and_resolve_back() {
+        let codes: HashSet<_> = NUMERIC_CODES.iter().map(|(code, _)| *code).collect();
+        assert_eq!(codes.len(), NUMERIC_CODES.len());
+
+        for (code, speciality) in NUMERIC_CODES {
+            assert_eq!(code.len(), 3, "{code}");
+            assert_eq!(Speciality::from_numeric_code(code), Some(*speciality));
+        }
+        assert_eq!(Speciality::from_numeric_code("F2"), None);
+    }
+}
@@ -0,0 +1,52 @@
+-- CAMPAIGN
+-- Offers, applicants and applications belong to one admission year; their EDBO ids are
+-- only unique within it. Institutions and lookup tables stay global.
+CREATE TABLE IF NOT EXISTS common.campaign (
+    year INT2 PRIMARY KEY
+);
+
+INSERT INTO common.campaign (year) VALUES (2025), (2026);
+
+-- Drop every key that changes shape, children first
+ALTER TABLE scraped.application DROP CONSTRAINT fk_application_user;
+ALTER TABLE scraped.application DROP CONSTRAINT fk_application_offer;
+ALTER TABLE common.offers_institutions DROP CONSTRAINT fk_offer_many;
+
+ALTER TABLE scraped.application DROP CONSTRAINT application_pkey;
+ALTER TABLE scraped.applicant DROP CONSTRAINT applicant_pkey;
+ALTER TABLE common.offers_institutions DROP CONSTRAINT offers_institutions_pkey;
+ALTER TABLE common.offer DROP CONSTRAINT offer_pkey;
+
+-- Before this migration only the legacy (2025) import could write these tables
+ALTER TABLE common.offer ADD COLUMN campaign_year INT2;
+ALTER TABLE common.offers_institutions ADD COLUMN campaign_year INT2;
+ALTER TABLE scraped.applicant ADD COLUMN campaign_year INT2;
+ALTER TABLE scraped.application ADD COLUMN campaign_year INT2;
+
+UPDATE common.offer SET campaign_year = 2025;
+UPDATE common.offers_institutions SET campaign_year = 2025;
+UPDATE scraped.applicant SET campaign_year = 2025;
+UPDATE scraped.application SET campaign_year = 2025;
+
+ALTER TABLE common.offer ALTER COLUMN campaign_year SET NOT NULL;
+ALTER TABLE common.offers_institutions ALTER COLUMN campaign_year SET NOT NULL;
+ALTER TABLE scraped.applicant ALTER COLUMN campaign_year SET NOT NULL;
+ALTER TABLE scraped.application ALTER COLUMN campaign_year SET NOT NULL;
+
+-- Recreate keys scoped by campaign
+ALTER TABLE common.offer
+    ADD PRIMARY KEY (campaign_year, id),
+    ADD CONSTRAINT fk_offer_campaign FOREIGN KEY (campaign_year) REFERENCES common.campaign(year);
+
+ALTER TABLE common.offers_institutions
+    ADD PRIMARY KEY (campaign_year, university_id, offer_id),
+    ADD CONSTRAINT fk_offer_many FOREIGN KEY (campaign_year, offer_id) REFERENCES common.offer(campaign_year, id);
+
+ALTER TABLE scraped.applicant
+    ADD PRIMARY KEY (campaign_year, id),
+    ADD CONSTRAINT fk_applicant_campaign FOREIGN KEY (campaign_year) REFERENCES common.campaign(year);
+
+ALTER TABLE scraped.application
+    ADD PRIMARY KEY (campaign_year, offer_id, number_in_list),
+    ADD CONSTRAINT fk_application_offer FOREIGN KEY (campaign_year, offer_id) REFERENCES common.offer(campaign_year, id),
+    ADD CONSTRAINT fk_application_user FOREIGN KEY (campaign_year, user_id) REFERENCES scraped.applicant(campaign_year, id);
@@ -0,0 +1,21 @@
+-- GRADE COMPONENTS
+-- `scraped.applicant.grade_components` moves from a bare array of contributions (the
+-- `edbo_core` shape, e.g. [26.8, 150.0]) to `model::applicant::grade::GradeComponents`:
+-- {"version": 1, "components": [{"subject": null, "formula": null, "contribution": "26.8"}]}
+UPDATE scraped.applicant
+SET grade_components = jsonb_build_object(
+    'version', 1,
+    'components', COALESCE(
+        (SELECT jsonb_agg(jsonb_build_object(
+            'subject', NULL,
+            'formula', NULL,
+            'contribution', component #>> '{}'
+        ) ORDER BY position)
+         FROM jsonb_array_elements(grade_components) WITH ORDINALITY AS t(component, position)),
+        '[]'::jsonb
+    )
+)
+WHERE jsonb_typeof(grade_components) = 'array';
+
+ALTER TABLE scraped.applicant
+    ADD CONSTRAINT grade_components_versioned CHECK (grade_components ? 'version');
@@ -0,0 +1,19 @@
+-- SCHEDULER
+-- One row per scheduled scrape-and-place cycle, including the ones skipped because
+-- another instance held the scrape lock.
+CREATE SCHEMA IF NOT EXISTS scheduler;
+
+CREATE TABLE IF NOT EXISTS scheduler.run (
+    id SERIAL PRIMARY KEY,
+    campaign_year INT2 NOT NULL,
+    scheduled_at TIMESTAMPTZ NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    finished_at TIMESTAMPTZ,
+    status VARCHAR NOT NULL,
+    error VARCHAR,
+
+    CONSTRAINT fk_run_campaign FOREIGN KEY (campaign_year) REFERENCES common.campaign(year),
+    CONSTRAINT run_status CHECK (status IN ('running', 'succeeded', 'failed', 'skipped'))
+);
+
+CREATE INDEX IF NOT EXISTS run_started_at ON scheduler.run (started_at DESC);
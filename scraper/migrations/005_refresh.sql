@@ -0,0 +1,11 @@
+-- REFRESH
+-- When each entity's last refresh from EDBO committed, per campaign; `/freshness` reports
+-- its age. Registry data (institutions) is recorded under the campaign being processed.
+CREATE TABLE IF NOT EXISTS common.refresh (
+    campaign_year INT2 NOT NULL,
+    entity VARCHAR NOT NULL,
+    refreshed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    PRIMARY KEY (campaign_year, entity),
+    CONSTRAINT fk_refresh_campaign FOREIGN KEY (campaign_year) REFERENCES common.campaign(year)
+);
@@ -0,0 +1,8 @@
+-- SCHEDULER: INTERRUPTED RUNS
+-- A cycle stopped by shutdown, or by the instance dying, is `interrupted` rather than
+-- failed. `checkpoint` is the last stage that committed before it stopped.
+ALTER TABLE scheduler.run ADD COLUMN IF NOT EXISTS checkpoint VARCHAR;
+
+ALTER TABLE scheduler.run DROP CONSTRAINT run_status;
+ALTER TABLE scheduler.run ADD CONSTRAINT run_status
+    CHECK (status IN ('running', 'succeeded', 'failed', 'skipped', 'interrupted'));
@@ -0,0 +1,63 @@
+-- ROLES
+-- One group role per component, so each connects with only what it needs (see
+-- REFACTOR_PLAN "Database roles"). NOLOGIN: deployments create login users and grant
+-- them one of these, passwords never live in migrations. Roles are cluster-wide, so
+-- another database on the same server may have created them already.
+-- Needs CREATEROLE for whoever runs migrations, the owner of everything below.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'abit_scraper') THEN
+        CREATE ROLE abit_scraper NOLOGIN;
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'abit_placement') THEN
+        CREATE ROLE abit_placement NOLOGIN;
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'abit_api') THEN
+        CREATE ROLE abit_api NOLOGIN;
+    END IF;
+END
+$$;
+
+-- API
+-- What the public API may read of `scraped`. Views run with their owner's privileges, so
+-- `abit_api` reads these without any grant on `scraped`, and a column added there isn't
+-- served until a view here picks it up. Whatever a view selects is public: names and
+-- grade components stay out, applicants are only their ids.
+CREATE SCHEMA IF NOT EXISTS api;
+
+CREATE OR REPLACE VIEW api.applicant AS
+    SELECT campaign_year, id FROM scraped.applicant;
+
+CREATE OR REPLACE VIEW api.application AS
+    SELECT campaign_year, offer_id, number_in_list, user_id, grade, priority_code, status_id
+    FROM scraped.application;
+
+-- Nothing by default, then only what each role needs
+REVOKE ALL ON SCHEMA common, scraped, scheduler, api FROM PUBLIC;
+
+-- Scraper: writes `common` and `scraped`, and the scheduler's run history
+GRANT USAGE ON SCHEMA common, scraped, scheduler TO abit_scraper;
+GRANT SELECT, INSERT, UPDATE, DELETE
+    ON ALL TABLES IN SCHEMA common, scraped, scheduler TO abit_scraper;
+GRANT USAGE, SELECT ON ALL SEQUENCES IN SCHEMA scheduler TO abit_scraper;
+
+-- Placement: reads its input. Its own schema and grants come with its migrations.
+GRANT USAGE ON SCHEMA common, scraped TO abit_placement;
+GRANT SELECT ON ALL TABLES IN SCHEMA common, scraped TO abit_placement;
+
+-- API: `common` and the views, never `scraped`
+GRANT USAGE ON SCHEMA common, api TO abit_api;
+GRANT SELECT ON ALL TABLES IN SCHEMA common, api TO abit_api;
+
+-- Readiness checks compare applied migrations with the embedded ones
+GRANT SELECT ON _sqlx_migrations TO abit_scraper, abit_api;
+
+-- Tables later migrations create get the same grants
+ALTER DEFAULT PRIVILEGES IN SCHEMA common, scraped, scheduler
+    GRANT SELECT, INSERT, UPDATE, DELETE ON TABLES TO abit_scraper;
+ALTER DEFAULT PRIVILEGES IN SCHEMA scheduler
+    GRANT USAGE, SELECT ON SEQUENCES TO abit_scraper;
+ALTER DEFAULT PRIVILEGES IN SCHEMA common, scraped
+    GRANT SELECT ON TABLES TO abit_placement;
+ALTER DEFAULT PRIVILEGES IN SCHEMA common, api
+    GRANT SELECT ON TABLES TO abit_api;
@@ -0,0 +1,14 @@
+-- GRADE COMPONENTS, VERSION 2
+-- Drops `subject` from every component: EDBO never names the test, so it was always null.
+-- {"version": 2, "components": [{"formula": null, "contribution": "26.8"}]}
+UPDATE scraped.applicant
+SET grade_components = jsonb_build_object(
+    'version', 2,
+    'components', COALESCE(
+        (SELECT jsonb_agg(component - 'subject' ORDER BY position)
+         FROM jsonb_array_elements(grade_components -> 'components')
+             WITH ORDINALITY AS t(component, position)),
+        '[]'::jsonb
+    )
+)
+WHERE grade_components ->> 'version' = '1';
@@ -0,0 +1,15 @@
+-- GRADE COMPONENTS, VERSION 3
+-- Brings `subject` back, parsed from the label after kv's number. Stored rows never kept
+-- the label, so theirs is null.
+-- {"version": 3, "components": [{"subject": null, "formula": null, "contribution": "26.8"}]}
+UPDATE scraped.applicant
+SET grade_components = jsonb_build_object(
+    'version', 3,
+    'components', COALESCE(
+        (SELECT jsonb_agg(component || '{"subject": null}'::jsonb ORDER BY position)
+         FROM jsonb_array_elements(grade_components -> 'components')
+             WITH ORDINALITY AS t(component, position)),
+        '[]'::jsonb
+    )
+)
+WHERE grade_components ->> 'version' = '2';
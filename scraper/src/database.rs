@@ -1,4 +1,3 @@
-use model::schemas;
 use sqlx::PgPool;
 use thiserror::Error;
 
@@ -16,67 +15,40 @@ impl Database {
         &self.pool
     }
 
+    // The migrator tracks what's already applied, so this is a no-op on an
+    // up-to-date database and only runs the new ones on an older one.
     pub async fn configure(&self) -> Result<(), DbError> {
-        let is_migration_needed = self.is_migration_needed().await?;
-        if is_migration_needed {
-            log::info!("There are no tables. Need to do migration...");
-            sqlx::migrate!().run(&self.pool).await?;
-            log::info!("Migration done successfully.");
-        }
+        log::info!("Applying pending migrations...");
+        sqlx::migrate!().run(&self.pool).await?;
+        log::info!("Database schema is up to date.");
 
         Ok(())
     }
 
-    async fn is_migration_needed(&self) -> Result<bool, DbError> {
-        let exists_schema_common: bool = sqlx::query_scalar(
-            "SELECT EXISTS ( SELECT 1 FROM information_schema.schemata WHERE schema_name = $1)"
-        )
-            .bind(schemas::COMMON)
-            .fetch_one(&self.pool)
-            .await
-            .map_err(DbError::SchemaExistsValidation)?;
-
-        let exists_schema_scraped: bool = sqlx::query_scalar(
-            "SELECT EXISTS ( SELECT 1 FROM information_schema.schemata WHERE schema_name = $1)"
-        )
-            .bind(schemas::SCRAPED)
-            .fetch_one(&self.pool)
-            .await
-            .map_err(DbError::SchemaExistsValidation)?;
-
-        // Check if the common and scraped schema contains any user-defined base tables
-        let has_tables_common: bool = sqlx::query_scalar(
-            "SELECT EXISTS (
-            SELECT 1
-            FROM information_schema.tables
-            WHERE table_schema = $1
-            AND table_type = 'BASE TABLE'
-        )",
-        )
-        .bind(schemas::COMMON)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::TableAmountValidation)?;
+    // Embedded migrations that aren't applied yet, without applying them. Read-only, for
+    // readiness checks.
+    pub async fn pending_migrations(&self) -> Result<usize, DbError> {
+        let applied: Vec<i64> =
+            sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(DbError::AppliedMigrations)?;
 
-        let has_tables_scraped: bool = sqlx::query_scalar(
-            "SELECT EXISTS (
-            SELECT 1
-            FROM information_schema.tables
-            WHERE table_schema = $1
-            AND table_type = 'BASE TABLE'
-        )",
-        )
-        .bind(schemas::SCRAPED)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(DbError::TableAmountValidation)?;
+        let pending = sqlx::migrate!()
+            .iter()
+            .filter(|migration| !applied.contains(&migration.version))
+            .count();
 
-        let result = !(exists_schema_common
-            && exists_schema_scraped
-            && has_tables_scraped
-            && has_tables_common);
+        Ok(pending)
+    }
 
-        Ok(result)
+    // Scraping may connect as a role that can't change the schema, so it doesn't
+    // migrate, only refuses to write into an outdated one
+    pub async fn ensure_migrated(&self) -> Result<(), DbError> {
+        match self.pending_migrations().await? {
+            0 => Ok(()),
+            pending => Err(DbError::Pending(pending)),
+        }
     }
 }
 
@@ -85,9 +57,9 @@ pub enum DbError {
     #[error("Failed to run database migrations. {0}")]
     Migration(#[from] sqlx::migrate::MigrateError),
 
-    #[error("Failed to execute query that checks if schema exists. {0}")]
-    SchemaExistsValidation(sqlx::Error),
+    #[error("Failed to read applied migrations. {0}")]
+    AppliedMigrations(sqlx::Error),
 
-    #[error("Failed to execute query that checks amount of tables. {0}")]
-    TableAmountValidation(sqlx::Error),
+    #[error("{0} migrations aren't applied yet, run `migrate` first.")]
+    Pending(usize),
 }
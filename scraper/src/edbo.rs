@@ -0,0 +1,36 @@
+use crate::telemetry;
+use reqwest::StatusCode;
+use std::time::Instant;
+
+// Every EDBO request goes through here, so each endpoint is counted and timed the same
+// way. `endpoint` is a short stable name for labels, not the URL with its query.
+// A non-2xx status is an error: EDBO's error pages aren't JSON and would otherwise
+// surface as a deserialization failure.
+pub async fn get_text(
+    endpoint: &'static str, url: &str,
+) -> Result<String, reqwest::Error> {
+    let started = Instant::now();
+    let response = reqwest::get(url).await;
+
+    let status = response.as_ref().map_or_else(
+        |_| String::from("error"),
+        |response| response.status().as_str().to_string(),
+    );
+    metrics::counter!(telemetry::EDBO_REQUESTS, "endpoint" => endpoint, "status" => status)
+        .increment(1);
+    if response
+        .as_ref()
+        .is_ok_and(|response| response.status() == StatusCode::TOO_MANY_REQUESTS)
+    {
+        metrics::counter!(telemetry::EDBO_THROTTLED, "endpoint" => endpoint).increment(1);
+    }
+
+    let text = match response.and_then(reqwest::Response::error_for_status) {
+        Ok(response) => response.text().await,
+        Err(error) => Err(error),
+    };
+    metrics::histogram!(telemetry::EDBO_REQUEST_DURATION, "endpoint" => endpoint)
+        .record(started.elapsed());
+
+    text
+}
@@ -1,5 +1,6 @@
 use crate::database::DbError;
 use crate::institution::errors::InstitutionError;
+use crate::legacy::errors::LegacyImportError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,4 +10,28 @@ pub enum ScraperError {
 
     #[error("Institution. {0}")]
     Institution(#[from] InstitutionError),
+
+    #[error("Legacy import. {0}")]
+    LegacyImport(#[from] LegacyImportError),
+}
+
+// Coarse cause of a failure, for callers that react differently to each — a network
+// error is worth retrying, a data error needs someone to look at EDBO's output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Database,
+    Network,
+    Data,
+    // Stopped by the shutdown token, nothing went wrong
+    Cancelled,
+}
+
+impl ScraperError {
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Database(_) => ErrorKind::Database,
+            Self::Institution(error) => error.kind(),
+            Self::LegacyImport(error) => error.kind(),
+        }
+    }
 }
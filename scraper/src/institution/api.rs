@@ -1,19 +1,22 @@
+use crate::edbo;
 use crate::institution::dto::InstitutionDto;
 use crate::institution::errors::InstitutionError;
+use model::campaign::Campaign;
 use model::institution::category::InstitutionCategory;
 use model::region::Region;
 
 pub struct InstitutionApi;
 
 impl InstitutionApi {
+    pub const CATEGORY: InstitutionCategory = InstitutionCategory::HigherEducation;
+    const REGION: Region = Region::Every;
+    const ENDPOINT: &str = "universities";
+
     pub async fn list() -> Result<Vec<InstitutionDto>, InstitutionError> {
-        let url = Self::build_url();
-        let text = reqwest::get(url)
-            .await
-            .map_err(InstitutionError::Request)?
-            .text()
+        let url = Self::build_url()?;
+        let text = edbo::get_text(Self::ENDPOINT, &url)
             .await
-            .map_err(InstitutionError::RequestText)?;
+            .map_err(InstitutionError::Request)?;
         let institutions =
             serde_json::from_str(&text).map_err(InstitutionError::Deserializing)?;
 
@@ -24,11 +27,19 @@ impl InstitutionApi {
     // `ut` = institution category filter (see model::institution::category::InstitutionCategory).
     // Only category 1 (institutions of higher education) offers master's programs,
     // so that's the only category this project needs — not looping over the rest.
-    fn build_url() -> String {
-        format!(
-            "https://registry.edbo.gov.ua/api/opendata/universities?rg={}&ut={}&exp=json",
-            i16::from(Region::Every),
-            i16::from(InstitutionCategory::HigherEducation)
-        )
+    // The registry serves current data whatever campaign is processed, so the codes
+    // are always the current campaign's.
+    fn build_url() -> Result<String, InstitutionError> {
+        let campaign = Campaign::CURRENT;
+        let region = Self::REGION
+            .edbo_code(campaign)
+            .ok_or(InstitutionError::UnmappedRegion(Self::REGION, campaign))?;
+        let category = Self::CATEGORY
+            .edbo_code(campaign)
+            .ok_or(InstitutionError::UnmappedCategory(Self::CATEGORY, campaign))?;
+
+        Ok(format!(
+            "https://registry.edbo.gov.ua/api/opendata/universities?rg={region}&ut={category}&exp=json"
+        ))
     }
 }
@@ -6,6 +6,9 @@ use model::region::Region;
 use serde::Deserialize;
 use std::str::FromStr;
 
+// The registry answers with labels, not `rg`/`ut` codes, so category, ownership and
+// region parse from their Ukrainian names. Per-year codes only apply to the query
+// (`InstitutionApi::build_url`) and to sources that store codes (the legacy import).
 #[derive(Debug, Deserialize)]
 pub struct InstitutionDto {
     #[serde(rename = "Назва закладу освіти")]
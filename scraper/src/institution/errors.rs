@@ -1,3 +1,7 @@
+use crate::errors::ErrorKind;
+use model::campaign::Campaign;
+use model::institution::category::InstitutionCategory;
+use model::region::Region;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -12,26 +16,40 @@ pub enum InstitutionError {
     #[error("Region DTO Parsing. {0}")]
     RegionDto(strum::ParseError),
 
+    #[error(
+        "Institution {0} has category {1:?}, not the requested one. EDBO codes changed?"
+    )]
+    UnexpectedCategory(i16, InstitutionCategory),
+
     // Serializing
     #[error("Deserializing. {0}")]
     Deserializing(serde_json::Error),
 
     // API
+    #[error("Region {0:?} has no EDBO code in campaign {1}.")]
+    UnmappedRegion(Region, Campaign),
+
+    #[error("Category {0:?} has no EDBO code in campaign {1}.")]
+    UnmappedCategory(InstitutionCategory, Campaign),
+
     #[error("Request. {0}")]
     Request(reqwest::Error),
 
-    #[error("Request Text. {0}")]
-    RequestText(reqwest::Error),
-
     // SQL
     #[error("Find All query. {0}")]
     FindAll(sqlx::Error),
 
-    #[error("Insert query. {0}")]
-    Insert(sqlx::Error),
+    #[error("Transaction. {0}")]
+    Transaction(sqlx::Error),
+
+    #[error("Upsert query. {0}")]
+    Upsert(sqlx::Error),
 
-    #[error("Is table empty check. {0}")]
-    IsEmpty(sqlx::Error),
+    #[error("Record refresh. {0}")]
+    RecordRefresh(sqlx::Error),
+
+    #[error("Is refreshed check. {0}")]
+    IsRefreshed(sqlx::Error),
 
     #[error("Inconsistent data in category dictionary. {0}")]
     InconsistentCategoryData(String),
@@ -41,4 +59,31 @@ pub enum InstitutionError {
 
     #[error("Inconsistent data in region dictionary. {0}")]
     InconsistentRegionData(String),
+
+    #[error("Cancelled by shutdown, the stored registry is unchanged.")]
+    Cancelled,
+}
+
+impl InstitutionError {
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Request(_) => ErrorKind::Network,
+            Self::FindAll(_)
+            | Self::Transaction(_)
+            | Self::Upsert(_)
+            | Self::RecordRefresh(_)
+            | Self::IsRefreshed(_) => ErrorKind::Database,
+            Self::CategoryDto(_)
+            | Self::OwnershipFormDto(_)
+            | Self::RegionDto(_)
+            | Self::UnexpectedCategory(..)
+            | Self::Deserializing(_)
+            | Self::UnmappedRegion(..)
+            | Self::UnmappedCategory(..)
+            | Self::InconsistentCategoryData(_)
+            | Self::InconsistentOwnershipFormData(_)
+            | Self::InconsistentRegionData(_) => ErrorKind::Data,
+            Self::Cancelled => ErrorKind::Cancelled,
+        }
+    }
 }
@@ -1,58 +1,164 @@
 use crate::database::Database;
 use crate::institution::api::InstitutionApi;
+use crate::institution::dto::InstitutionDto;
 use crate::institution::errors::InstitutionError;
+use crate::refresh;
+use crate::telemetry;
+use model::campaign::Campaign;
 use model::institution::Institution;
 use model::institution::category::InstitutionCategory;
 use model::institution::ownership::OwnershipForm;
 use model::region::Region;
+use sqlx::postgres::PgTransaction;
+use tokio_util::sync::CancellationToken;
 
 pub struct InstitutionService<'a> {
     database: &'a Database,
+    campaign: Campaign,
+    shutdown: &'a CancellationToken,
 }
 
 impl<'a> InstitutionService<'a> {
-    pub const fn new(database: &'a Database) -> Self {
-        Self { database }
+    pub const ENTITY: &'static str = "institution";
+
+    pub const fn new(
+        database: &'a Database, campaign: Campaign, shutdown: &'a CancellationToken,
+    ) -> Self {
+        Self {
+            database,
+            campaign,
+            shutdown,
+        }
     }
 
+    // Rows alone don't say the registry was fetched: the legacy import fills the same
+    // global table. So it's the campaign's refresh that's trusted, not a populated table.
     pub async fn get(&self) -> Result<Vec<Institution>, InstitutionError> {
-        if self.is_empty().await? {
-            log::info!("Institutions table is empty, fetching from EDBO...");
-            let dtos = InstitutionApi::list().await?;
+        if self.is_refreshed().await? {
+            log::info!(entity = "institution"; "Institutions are already refreshed for campaign {}, reading from DB...", self.campaign);
+            self.find_all().await
+        } else {
+            log::info!(entity = "institution"; "Institutions were never refreshed for campaign {}, fetching from EDBO...", self.campaign);
+            self.refresh().await
+        }
+    }
 
-            let mut institutions = Vec::with_capacity(dtos.len());
-            for dto in dtos {
-                institutions.push(Institution::try_from(dto)?);
+    // Fetches the registry again whatever is stored, updating known institutions in
+    // place — they're referenced by offers, so they can't be deleted and re-inserted.
+    // One transaction: readers see the previous registry until the new one is complete.
+    // A shutdown abandons the request, or rolls back before the next row.
+    pub async fn refresh(&self) -> Result<Vec<Institution>, InstitutionError> {
+        let dtos = self
+            .shutdown
+            .run_until_cancelled(InstitutionApi::list())
+            .await
+            .ok_or(InstitutionError::Cancelled)??;
+        metrics::counter!(telemetry::ENTITIES_FETCHED, "entity" => Self::ENTITY)
+            .increment(dtos.len() as u64);
+
+        let institutions = Self::validate_all(dtos)?;
+
+        let mut transaction = self
+            .database
+            .pool()
+            .begin()
+            .await
+            .map_err(InstitutionError::Transaction)?;
+
+        for institution in &institutions {
+            if self.shutdown.is_cancelled() {
+                transaction
+                    .rollback()
+                    .await
+                    .map_err(InstitutionError::Transaction)?;
+                return Err(InstitutionError::Cancelled);
             }
+            Self::upsert(&mut transaction, institution).await?;
+        }
+        refresh::record(&mut transaction, self.campaign, Self::ENTITY)
+            .await
+            .map_err(InstitutionError::RecordRefresh)?;
+
+        transaction
+            .commit()
+            .await
+            .map_err(InstitutionError::Transaction)?;
 
-            for institution in &institutions {
-                self.insert(institution).await?;
+        log::info!(entity = "institution", count = institutions.len(); "Stored {} institutions.", institutions.len());
+        Ok(institutions)
+    }
+
+    // Every DTO is checked, so the rejected count and the log cover the whole response.
+    // Any rejection still fails the refresh: a partial registry isn't stored.
+    fn validate_all(
+        dtos: Vec<InstitutionDto>,
+    ) -> Result<Vec<Institution>, InstitutionError> {
+        let total = dtos.len();
+        let mut institutions = Vec::with_capacity(total);
+        let mut rejected = Vec::new();
+        for dto in dtos {
+            match Self::validate(dto) {
+                Ok(institution) => institutions.push(institution),
+                Err(error) => {
+                    log::warn!(entity = "institution"; "Rejected institution. {error}");
+                    rejected.push(error);
+                },
             }
+        }
 
-            log::info!("Inserted {} institutions.", institutions.len());
-            Ok(institutions)
-        } else {
-            log::info!("Institutions table is already populated, reading from DB...");
-            self.find_all().await
+        let count = rejected.len();
+        metrics::counter!(telemetry::ENTITIES_REJECTED, "entity" => Self::ENTITY)
+            .increment(count as u64);
+
+        let Some(first) = rejected.into_iter().next() else {
+            return Ok(institutions);
+        };
+        log::warn!(entity = "institution", count = count; "Rejected {count} of {total} institutions, storing none.");
+        Err(first)
+    }
+
+    fn validate(dto: InstitutionDto) -> Result<Institution, InstitutionError> {
+        let institution = Institution::try_from(dto)?;
+        // A renumbered `ut` would silently return another category.
+        if institution.category != InstitutionApi::CATEGORY {
+            return Err(InstitutionError::UnexpectedCategory(
+                institution.id,
+                institution.category,
+            ));
         }
+
+        Ok(institution)
     }
 
-    async fn is_empty(&self) -> Result<bool, InstitutionError> {
-        let empty =
-            sqlx::query_scalar!("SELECT NOT EXISTS (SELECT 1 FROM common.institution)")
-                .fetch_one(self.database.pool())
-                .await
-                .map_err(InstitutionError::IsEmpty)?;
+    async fn is_refreshed(&self) -> Result<bool, InstitutionError> {
+        let refreshed = sqlx::query_scalar!(
+            "SELECT EXISTS (SELECT 1 FROM common.refresh
+                            WHERE campaign_year = $1 AND entity = $2)",
+            i16::from(self.campaign),
+            Self::ENTITY,
+        )
+        .fetch_one(self.database.pool())
+        .await
+        .map_err(InstitutionError::IsRefreshed)?;
 
-        Ok(empty.unwrap_or(true))
+        Ok(refreshed.unwrap_or(false))
     }
 
-    async fn insert(&self, institution: &Institution) -> Result<(), InstitutionError> {
+    async fn upsert(
+        transaction: &mut PgTransaction<'_>, institution: &Institution,
+    ) -> Result<(), InstitutionError> {
         sqlx::query!(
             "INSERT INTO common.institution
             (id, name, parent_id, short_name, english_name, is_from_crimea,
              registration_date, category_id, ownership_form_id, region_id)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+         ON CONFLICT (id) DO UPDATE SET
+            name = EXCLUDED.name, parent_id = EXCLUDED.parent_id,
+            short_name = EXCLUDED.short_name, english_name = EXCLUDED.english_name,
+            is_from_crimea = EXCLUDED.is_from_crimea,
+            registration_date = EXCLUDED.registration_date,
+            category_id = EXCLUDED.category_id,
+            ownership_form_id = EXCLUDED.ownership_form_id, region_id = EXCLUDED.region_id",
             institution.id,
             institution.title,
             institution.parent_id,
@@ -64,9 +170,9 @@ impl<'a> InstitutionService<'a> {
             i16::from(institution.ownership_form),
             institution.region.map(i16::from),
         )
-        .execute(self.database.pool())
+        .execute(&mut **transaction)
         .await
-        .map_err(InstitutionError::Insert)?;
+        .map_err(InstitutionError::Upsert)?;
 
         Ok(())
     }
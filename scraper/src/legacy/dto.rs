@@ -0,0 +1,88 @@
+use serde_json::Number;
+use sqlx::FromRow;
+use sqlx::types::{BigDecimal, Json};
+
+// Row shapes of the `edbo_core` (2025) schema. Everything lived in `public` there,
+// and lookup tables called their label column `description`/`name` instead of `title`.
+
+#[derive(Debug, FromRow)]
+pub struct LegacyLookupDto {
+    pub id: i16,
+    pub title: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyKnowledgeFieldDto {
+    pub code: String,
+    pub title: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacySpecialityDto {
+    pub code: String,
+    pub title: String,
+    pub knowledge_field: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyInstitutionDto {
+    pub id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub short_name: Option<String>,
+    pub english_name: Option<String>,
+    pub is_from_crimea: bool,
+    pub registration_year: Option<i16>,
+    pub category_id: i16,
+    pub ownership_form_id: i16,
+    pub region_id: i16,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyOfferDto {
+    pub id: i32,
+    pub title: String,
+    pub degree_id: i16,
+    pub education_program: String,
+    pub faculty: Option<String>,
+    pub speciality_code: String,
+    pub type_id: i16,
+    pub master_type: Option<String>,
+    pub study_form_id: i16,
+    pub license_volume: i32,
+    pub budgetary_places: i32,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyOfferInstitutionDto {
+    pub university_id: i32,
+    pub offer_id: i32,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyApplicantDto {
+    pub id: i32,
+    pub name: String,
+    // Contributions only, e.g. [26.8, 150.0]
+    pub grade_components: Json<Vec<Number>>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct LegacyApplicationDto {
+    pub number_in_list: i32,
+    pub status_id: i16,
+    pub grade: BigDecimal,
+    pub priority_code: i16,
+    pub offer_id: i32,
+    pub user_id: i32,
+}
+
+// An application next to what its score is recomputed from
+#[derive(Debug, FromRow)]
+pub struct LegacyScoreDto {
+    pub offer_id: i32,
+    pub user_id: i32,
+    pub grade: BigDecimal,
+    pub speciality_code: String,
+    pub grade_components: Json<Vec<Number>>,
+}
@@ -0,0 +1,55 @@
+use crate::errors::ErrorKind;
+use model::applicant::errors::GradeComponentError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LegacyImportError {
+    // Source
+    #[error("Reading \"{0}\" from the legacy database. {1}")]
+    Read(&'static str, sqlx::Error),
+
+    #[error("Institution ID {0} does not fit into the current schema.")]
+    InstitutionIdOutOfRange(i32),
+
+    #[error("Region code {0} is unknown for the 2025 campaign.")]
+    UnmappedRegionCode(i16),
+
+    #[error("Institution category code {0} is unknown for the 2025 campaign.")]
+    UnmappedCategoryCode(i16),
+
+    #[error("Grade components of applicant {0}. {1}")]
+    GradeComponent(i32, GradeComponentError),
+
+    #[error("Serializing grade components of applicant {0}. {1}")]
+    GradeComponentsSerialization(i32, serde_json::Error),
+
+    // SQL
+    #[error("Transaction. {0}")]
+    Transaction(sqlx::Error),
+
+    #[error("Insert into \"{0}\". {1}")]
+    Insert(&'static str, sqlx::Error),
+
+    #[error("Checking whether the legacy campaign is imported. {0}")]
+    CheckImported(sqlx::Error),
+
+    #[error("Cancelled by shutdown, nothing was imported.")]
+    Cancelled,
+}
+
+impl LegacyImportError {
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Read(..)
+            | Self::Transaction(_)
+            | Self::Insert(..)
+            | Self::CheckImported(_) => ErrorKind::Database,
+            Self::InstitutionIdOutOfRange(_)
+            | Self::UnmappedRegionCode(_)
+            | Self::UnmappedCategoryCode(_)
+            | Self::GradeComponent(..)
+            | Self::GradeComponentsSerialization(..) => ErrorKind::Data,
+            Self::Cancelled => ErrorKind::Cancelled,
+        }
+    }
+}
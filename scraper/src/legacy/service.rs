@@ -0,0 +1,551 @@
+use crate::database::Database;
+use crate::legacy::dto::{
+    LegacyApplicantDto, LegacyApplicationDto, LegacyInstitutionDto,
+    LegacyKnowledgeFieldDto, LegacyLookupDto, LegacyOfferDto, LegacyOfferInstitutionDto,
+    LegacyScoreDto, LegacySpecialityDto,
+};
+use crate::legacy::errors::LegacyImportError;
+use crate::telemetry;
+use model::applicant::grade::{GradeComponent, GradeComponents};
+use model::application::priority::Priority;
+use model::application::score::ScoreVerifier;
+use model::campaign::Campaign;
+use model::institution::category::InstitutionCategory;
+use model::region::Region;
+use model::speciality::Speciality;
+use serde_json::Number;
+use sqlx::PgPool;
+use sqlx::postgres::PgTransaction;
+use sqlx::types::JsonValue;
+use std::collections::BTreeMap;
+use tokio_util::sync::CancellationToken;
+
+// Lookup tables share one shape on both sides: (target table, source query, insert).
+const LOOKUPS: [(&str, &str, &str); 7] = [
+    (
+        "common.institution_category",
+        "SELECT id, description AS title FROM institution_category",
+        "INSERT INTO common.institution_category (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.ownership_form",
+        "SELECT id, description AS title FROM ownership_form",
+        "INSERT INTO common.ownership_form (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.region",
+        "SELECT id, name AS title FROM region",
+        "INSERT INTO common.region (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.application_status",
+        "SELECT id, description AS title FROM application_status",
+        "INSERT INTO common.application_status (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.study_form",
+        "SELECT id, description AS title FROM study_form",
+        "INSERT INTO common.study_form (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.degree",
+        "SELECT id, description AS title FROM degree",
+        "INSERT INTO common.degree (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+    (
+        "common.offer_type",
+        "SELECT id, description AS title FROM offer_type",
+        "INSERT INTO common.offer_type (id, title) VALUES ($1, $2)
+         ON CONFLICT (id) DO NOTHING",
+    ),
+];
+
+// Copies a 2025 `edbo_core` database into the `common`/`scraped` schemas,
+// tagging campaign-scoped rows with `CAMPAIGN`.
+// A dump has to be restored into its own database first (`pg_restore`), then passed
+// in as `source`. Everything happens in one transaction, and rows that already exist
+// are skipped, so an interrupted or repeated import is harmless.
+pub struct LegacyImportService<'a> {
+    database: &'a Database,
+    source: &'a PgPool,
+    shutdown: &'a CancellationToken,
+}
+
+impl<'a> LegacyImportService<'a> {
+    const CAMPAIGN: Campaign = Campaign::Year2025;
+
+    pub const fn new(
+        database: &'a Database, source: &'a PgPool, shutdown: &'a CancellationToken,
+    ) -> Self {
+        Self {
+            database,
+            source,
+            shutdown,
+        }
+    }
+
+    // Applications are the last table and everything commits at once, so any of them
+    // means a whole import went through
+    pub async fn is_imported(&self) -> Result<bool, LegacyImportError> {
+        let exists = sqlx::query_scalar!(
+            "SELECT EXISTS (SELECT 1 FROM scraped.application WHERE campaign_year = $1)",
+            i16::from(Self::CAMPAIGN),
+        )
+        .fetch_one(self.database.pool())
+        .await
+        .map_err(LegacyImportError::CheckImported)?;
+
+        Ok(exists.unwrap_or(false))
+    }
+
+    pub async fn import(&self) -> Result<(), LegacyImportError> {
+        let mut transaction = self
+            .database
+            .pool()
+            .begin()
+            .await
+            .map_err(LegacyImportError::Transaction)?;
+
+        // A shutdown stops it between tables. Returning drops the transaction, which
+        // rolls it back.
+        self.import_lookups(&mut transaction).await?;
+        self.proceed()?;
+        self.import_knowledge_fields(&mut transaction).await?;
+        self.proceed()?;
+        self.import_specialities(&mut transaction).await?;
+        self.proceed()?;
+        self.import_institutions(&mut transaction).await?;
+        self.proceed()?;
+        self.import_offers(&mut transaction).await?;
+        self.proceed()?;
+        self.import_offers_institutions(&mut transaction).await?;
+        self.proceed()?;
+        self.import_applicants(&mut transaction).await?;
+        self.proceed()?;
+        self.import_applications(&mut transaction).await?;
+
+        transaction
+            .commit()
+            .await
+            .map_err(LegacyImportError::Transaction)?;
+
+        Ok(())
+    }
+
+    async fn import_lookups(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        for (table, query, insert) in LOOKUPS {
+            let rows: Vec<LegacyLookupDto> = sqlx::query_as(query)
+                .fetch_all(self.source)
+                .await
+                .map_err(|error| LegacyImportError::Read(table, error))?;
+
+            for row in &rows {
+                sqlx::query(insert)
+                    .bind(row.id)
+                    .bind(&row.title)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|error| LegacyImportError::Insert(table, error))?;
+            }
+
+            Self::imported(table, rows.len());
+        }
+
+        Ok(())
+    }
+
+    async fn import_knowledge_fields(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "common.knowledge_field";
+
+        let rows: Vec<LegacyKnowledgeFieldDto> =
+            sqlx::query_as("SELECT code, name AS title FROM knowledge_field")
+                .fetch_all(self.source)
+                .await
+                .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            sqlx::query!(
+                "INSERT INTO common.knowledge_field (code, title) VALUES ($1, $2)
+                 ON CONFLICT (code) DO NOTHING",
+                row.code,
+                row.title,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    async fn import_specialities(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "common.speciality";
+
+        let rows: Vec<LegacySpecialityDto> =
+            sqlx::query_as("SELECT code, name AS title, knowledge_field FROM speciality")
+                .fetch_all(self.source)
+                .await
+                .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            sqlx::query!(
+                "INSERT INTO common.speciality (code, title, knowledge_field)
+                 VALUES ($1, $2, $3)
+                 ON CONFLICT (code) DO NOTHING",
+                row.code,
+                row.title,
+                row.knowledge_field,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    async fn import_institutions(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "common.institution";
+
+        let rows: Vec<LegacyInstitutionDto> = sqlx::query_as(
+            "SELECT id, name, parent_id, short_name, english_name, is_from_crimea,
+                registration_year, category_id, ownership_form_id, region_id
+         FROM institution",
+        )
+        .fetch_all(self.source)
+        .await
+        .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            let id = Self::institution_id(row.id)?;
+            let parent_id = row.parent_id.map(Self::institution_id).transpose()?;
+            // 2025 only kept the year, the current column stores EDBO's raw string.
+            let registration_date = row.registration_year.map(|year| year.to_string());
+            // Legacy rows hold EDBO's 2025 codes, the current tables hold our ids.
+            let category =
+                InstitutionCategory::from_edbo_code(row.category_id, Self::CAMPAIGN)
+                    .ok_or(LegacyImportError::UnmappedCategoryCode(row.category_id))?;
+            let region = Region::from_edbo_code(row.region_id, Self::CAMPAIGN)
+                .ok_or(LegacyImportError::UnmappedRegionCode(row.region_id))?;
+
+            sqlx::query!(
+                "INSERT INTO common.institution
+                (id, name, parent_id, short_name, english_name, is_from_crimea,
+                 registration_date, category_id, ownership_form_id, region_id)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             ON CONFLICT (id) DO NOTHING",
+                id,
+                row.name,
+                parent_id,
+                row.short_name,
+                row.english_name,
+                row.is_from_crimea,
+                registration_date,
+                i16::from(category),
+                row.ownership_form_id,
+                i16::from(region),
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    async fn import_offers(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "common.offer";
+
+        let rows: Vec<LegacyOfferDto> = sqlx::query_as(
+            "SELECT id, title, degree_id, education_program, faculty, speciality_code,
+                type_id, master_type, study_form_id, license_volume, budgetary_places
+         FROM offer",
+        )
+        .fetch_all(self.source)
+        .await
+        .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            sqlx::query!(
+                "INSERT INTO common.offer
+                (campaign_year, id, title, degree_id, education_program, faculty,
+                 speciality_code, type_id, master_type, study_form_id, license_volume,
+                 budgetary_places)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+             ON CONFLICT (campaign_year, id) DO NOTHING",
+                i16::from(Self::CAMPAIGN),
+                row.id,
+                row.title,
+                row.degree_id,
+                row.education_program,
+                row.faculty,
+                row.speciality_code,
+                row.type_id,
+                row.master_type,
+                row.study_form_id,
+                row.license_volume,
+                row.budgetary_places,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    async fn import_offers_institutions(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "common.offers_institutions";
+
+        // The legacy table had no FK to `offer`, so dangling links are dropped here.
+        let rows: Vec<LegacyOfferInstitutionDto> = sqlx::query_as(
+            "SELECT oi.university_id, oi.offer_id
+         FROM offers_institutions oi
+         JOIN offer o ON o.id = oi.offer_id",
+        )
+        .fetch_all(self.source)
+        .await
+        .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            let university_id = Self::institution_id(row.university_id)?;
+
+            sqlx::query!(
+                "INSERT INTO common.offers_institutions (campaign_year, university_id, offer_id)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (campaign_year, university_id, offer_id) DO NOTHING",
+                i16::from(Self::CAMPAIGN),
+                university_id,
+                row.offer_id,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    async fn import_applicants(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "scraped.applicant";
+
+        let rows: Vec<LegacyApplicantDto> =
+            sqlx::query_as("SELECT id, name, grade_components FROM applicant")
+                .fetch_all(self.source)
+                .await
+                .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            let grade_components = Self::grade_components(row)?;
+
+            sqlx::query!(
+                "INSERT INTO scraped.applicant (campaign_year, id, name, grade_components)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (campaign_year, id) DO NOTHING",
+                i16::from(Self::CAMPAIGN),
+                row.id,
+                row.name,
+                grade_components,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Ok(())
+    }
+
+    fn grade_components(
+        row: &LegacyApplicantDto,
+    ) -> Result<JsonValue, LegacyImportError> {
+        let components = Self::components(row.id, &row.grade_components)?;
+
+        serde_json::to_value(GradeComponents::new(components)).map_err(|error| {
+            LegacyImportError::GradeComponentsSerialization(row.id, error)
+        })
+    }
+
+    // `edbo_core` kept only each component's contribution, without its formula
+    fn components(
+        applicant: i32, contributions: &[Number],
+    ) -> Result<Vec<GradeComponent>, LegacyImportError> {
+        contributions
+            .iter()
+            .map(|contribution| GradeComponent::from_edbo(&contribution.to_string(), ""))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| LegacyImportError::GradeComponent(applicant, error))
+    }
+
+    async fn import_applications(
+        &self, transaction: &mut PgTransaction<'_>,
+    ) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "scraped.application";
+
+        let rows: Vec<LegacyApplicationDto> = sqlx::query_as(
+            "SELECT number_in_list, status_id, grade, priority_code, offer_id, user_id
+         FROM application",
+        )
+        .fetch_all(self.source)
+        .await
+        .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        for row in &rows {
+            sqlx::query!(
+                "INSERT INTO scraped.application
+                (campaign_year, number_in_list, status_id, grade, priority_code,
+                 offer_id, user_id)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             ON CONFLICT (campaign_year, offer_id, number_in_list) DO NOTHING",
+                i16::from(Self::CAMPAIGN),
+                row.number_in_list,
+                row.status_id,
+                row.grade,
+                row.priority_code,
+                row.offer_id,
+                row.user_id,
+            )
+            .execute(&mut **transaction)
+            .await
+            .map_err(|error| LegacyImportError::Insert(TABLE, error))?;
+        }
+
+        Self::imported(TABLE, rows.len());
+        Self::report_priority_violations(&rows);
+        self.report_score_mismatches().await
+    }
+
+    fn proceed(&self) -> Result<(), LegacyImportError> {
+        if self.shutdown.is_cancelled() {
+            return Err(LegacyImportError::Cancelled);
+        }
+
+        Ok(())
+    }
+
+    fn imported(table: &'static str, count: usize) {
+        metrics::counter!(telemetry::ENTITIES_FETCHED, "entity" => table)
+            .increment(count as u64);
+        log::info!(table = table, count = count; "Legacy import: {count} rows into {table}.");
+    }
+
+    // Imported as-is, but placement relies on each applicant's budget priorities being
+    // 1..=n without repeats, so anything else is worth knowing about up front.
+    fn report_priority_violations(rows: &[LegacyApplicationDto]) {
+        let mut priorities: BTreeMap<i32, Vec<Priority>> = BTreeMap::new();
+        for row in rows {
+            match Priority::try_from(row.priority_code) {
+                Ok(priority) => priorities.entry(row.user_id).or_default().push(priority),
+                Err(error) => log::warn!(
+                    applicant = row.user_id, offer = row.offer_id;
+                    "Legacy import: applicant {}, offer {}. {error}",
+                    row.user_id,
+                    row.offer_id
+                ),
+            }
+        }
+
+        let mut invalid = 0;
+        for (applicant, priorities) in &priorities {
+            let violations = Priority::validate(priorities);
+            if !violations.is_empty() {
+                invalid += 1;
+                log::debug!(
+                    applicant = *applicant;
+                    "Legacy import: applicant {applicant} priorities {violations:?}"
+                );
+            }
+        }
+
+        if invalid > 0 {
+            log::warn!(
+                "Legacy import: {invalid} of {} applicants have inconsistent budget priorities.",
+                priorities.len()
+            );
+        }
+    }
+
+    // Imported as-is too. Legacy components have no formulas, so this checks that they
+    // add up to the published grade under the speciality's multipliers: mismatches
+    // clustering in one speciality point at a multiplier `ScoreFormula` is missing.
+    async fn report_score_mismatches(&self) -> Result<(), LegacyImportError> {
+        const TABLE: &str = "scraped.application";
+
+        let rows: Vec<LegacyScoreDto> = sqlx::query_as(
+            "SELECT a.offer_id, a.user_id, a.grade, o.speciality_code, ap.grade_components
+         FROM application a
+         JOIN offer o ON o.id = a.offer_id
+         JOIN applicant ap ON ap.id = a.user_id",
+        )
+        .fetch_all(self.source)
+        .await
+        .map_err(|error| LegacyImportError::Read(TABLE, error))?;
+
+        let mut mismatched: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut unknown = 0;
+        for row in &rows {
+            let Some(speciality) = Speciality::from_code(&row.speciality_code) else {
+                unknown += 1;
+                continue;
+            };
+
+            let components = Self::components(row.user_id, &row.grade_components)?;
+            let discrepancies = ScoreVerifier::for_speciality(speciality, Self::CAMPAIGN)
+                .verify(&components, &row.grade);
+            if !discrepancies.is_empty() {
+                *mismatched.entry(speciality.code()).or_default() += 1;
+                log::debug!(
+                    applicant = row.user_id, offer = row.offer_id;
+                    "Legacy import: applicant {}, offer {} score {discrepancies:?}",
+                    row.user_id,
+                    row.offer_id
+                );
+            }
+        }
+
+        if unknown > 0 {
+            log::warn!(
+                "Legacy import: {unknown} applications are in specialities we don't know, their scores weren't checked."
+            );
+        }
+
+        let total: usize = mismatched.values().sum();
+        if total > 0 {
+            log::warn!(
+                "Legacy import: {total} of {} application scores don't match their components: {mismatched:?}.",
+                rows.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn institution_id(id: i32) -> Result<i16, LegacyImportError> {
+        i16::try_from(id).map_err(|_| LegacyImportError::InstitutionIdOutOfRange(id))
+    }
+}
@@ -1,33 +1,103 @@
 // Main Source: https://zakon.rada.gov.ua/laws/show/z0312-25#Text
 
-pub use crate::errors::ScraperError;
+pub use crate::errors::{ErrorKind, ScraperError};
+pub use crate::refresh::REFRESHED_ENTITIES;
+pub use crate::telemetry::describe_metrics;
 
 use crate::database::Database;
 use crate::institution::service::InstitutionService;
+use crate::legacy::service::LegacyImportService;
+use model::campaign::Campaign;
 use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug)]
 pub struct Scraper {
     database: Database,
+    campaign: Campaign,
+    shutdown: CancellationToken,
 }
 
 impl Scraper {
-    pub fn new(pool: &PgPool) -> Self {
+    pub fn new(pool: &PgPool, campaign: Campaign) -> Self {
         Self {
             database: Database::new(pool.clone()),
+            campaign,
+            shutdown: CancellationToken::new(),
         }
     }
 
-    pub async fn process(&self) -> Result<(), ScraperError> {
-        Database::configure(&self.database).await?;
+    // Once `shutdown` is cancelled, services stop issuing requests and roll back what
+    // they haven't committed, failing with `ErrorKind::Cancelled`. Without it, they
+    // always run to the end.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    pub async fn migrate(&self) -> Result<(), ScraperError> {
+        stage::timed("migrate", Database::configure(&self.database)).await?;
+
+        Ok(())
+    }
+
+    // For components that may not change the schema: refuses an outdated one instead
+    pub async fn ensure_migrated(&self) -> Result<(), ScraperError> {
+        Ok(self.database.ensure_migrated().await?)
+    }
+
+    pub async fn pending_migrations(&self) -> Result<usize, ScraperError> {
+        Ok(self.database.pending_migrations().await?)
+    }
+
+    // Without `refresh`, a registry already refreshed for the campaign is trusted and EDBO
+    // isn't asked again.
+    pub async fn scrape_institutions(&self, refresh: bool) -> Result<(), ScraperError> {
+        self.database.ensure_migrated().await?;
+        log::info!("Processing campaign {}.", self.campaign);
+
+        let service =
+            InstitutionService::new(&self.database, self.campaign, &self.shutdown);
+        let institutions = stage::timed("institutions", async {
+            if refresh {
+                log::info!(entity = "institution"; "Refreshing institutions from EDBO...");
+                service.refresh().await
+            } else {
+                service.get().await
+            }
+        })
+        .await?;
+        log::info!(entity = "institution", count = institutions.len(); "Institutions ready: {} total.", institutions.len());
+
+        Ok(())
+    }
+
+    // Without `again`, a campaign that's already imported is left as is: the import skips
+    // existing rows anyway, but still reads the whole source to find that out.
+    pub async fn import_legacy(
+        &self, source: &PgPool, again: bool,
+    ) -> Result<(), ScraperError> {
+        self.database.ensure_migrated().await?;
+
+        let service = LegacyImportService::new(&self.database, source, &self.shutdown);
+        if !again && service.is_imported().await? {
+            log::info!("Legacy campaign is already imported, skipping.");
+            return Ok(());
+        }
 
-        let institutions = InstitutionService::new(&self.database).get().await?;
-        log::info!("Institutions ready: {} total.", institutions.len());
+        stage::timed("legacy import", service.import()).await?;
+        log::info!("Legacy campaign imported.");
 
         Ok(())
     }
 }
 
 mod database;
+mod edbo;
 mod errors;
 mod institution;
+mod legacy;
+mod refresh;
+mod stage;
+mod telemetry;
@@ -0,0 +1,23 @@
+use crate::institution::service::InstitutionService;
+use model::campaign::Campaign;
+use sqlx::postgres::PgTransaction;
+
+// Entities refreshed from EDBO, each with a `common.refresh` row once it has been.
+// Anything consuming freshness expects every one of them.
+pub const REFRESHED_ENTITIES: &[&str] = &[InstitutionService::ENTITY];
+
+// In the refresh's own transaction, so the time only moves when the data does
+pub async fn record(
+    transaction: &mut PgTransaction<'_>, campaign: Campaign, entity: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT INTO common.refresh (campaign_year, entity) VALUES ($1, $2)
+         ON CONFLICT (campaign_year, entity) DO UPDATE SET refreshed_at = now()",
+        i16::from(campaign),
+        entity,
+    )
+    .execute(&mut **transaction)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::telemetry;
+use std::future::Future;
+use std::time::Instant;
+
+// Logs when a stage starts and ends, with `stage` and `elapsed_ms` fields for a log
+// stack to aggregate on, and records the duration. Reporting the error itself is left
+// to the caller.
+pub async fn timed<T, E>(
+    stage: &'static str, future: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    log::info!(stage = stage; "Stage {stage} started.");
+    let started = Instant::now();
+
+    let result = future.await;
+
+    let elapsed = started.elapsed();
+    let outcome = if result.is_ok() {
+        "succeeded"
+    } else {
+        "failed"
+    };
+    metrics::histogram!(telemetry::STAGE_DURATION, "stage" => stage, "outcome" => outcome)
+        .record(elapsed);
+
+    let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+    if result.is_ok() {
+        log::info!(stage = stage, elapsed_ms = elapsed_ms; "Stage {stage} finished in {elapsed_ms} ms.");
+    } else {
+        log::warn!(stage = stage, elapsed_ms = elapsed_ms; "Stage {stage} failed after {elapsed_ms} ms.");
+    }
+
+    result
+}
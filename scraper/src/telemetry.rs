@@ -0,0 +1,40 @@
+use metrics::{Unit, describe_counter, describe_histogram};
+
+// Metric names. Recording is a no-op until the binary installs a recorder, so the CLI's
+// one-shot commands pay nothing for them.
+pub const EDBO_REQUESTS: &str = "abit_edbo_requests_total";
+pub const EDBO_REQUEST_DURATION: &str = "abit_edbo_request_duration_seconds";
+pub const EDBO_THROTTLED: &str = "abit_edbo_throttled_total";
+pub const ENTITIES_FETCHED: &str = "abit_scraper_entities_fetched_total";
+pub const ENTITIES_REJECTED: &str = "abit_scraper_entities_rejected_total";
+pub const STAGE_DURATION: &str = "abit_scraper_stage_duration_seconds";
+
+// Has to run after the recorder is installed, descriptions sent before are lost
+pub fn describe_metrics() {
+    describe_counter!(
+        EDBO_REQUESTS,
+        "EDBO requests by endpoint and response status, `error` when none came back."
+    );
+    describe_histogram!(
+        EDBO_REQUEST_DURATION,
+        Unit::Seconds,
+        "EDBO request latency by endpoint, until the response body is read."
+    );
+    describe_counter!(
+        EDBO_THROTTLED,
+        "EDBO responses by endpoint that asked to slow down (429)."
+    );
+    describe_counter!(
+        ENTITIES_FETCHED,
+        "Entities read from EDBO or the legacy database, by entity."
+    );
+    describe_counter!(
+        ENTITIES_REJECTED,
+        "Fetched entities that failed validation, by entity."
+    );
+    describe_histogram!(
+        STAGE_DURATION,
+        Unit::Seconds,
+        "Scraper stage duration by stage and outcome."
+    );
+}
@@ -0,0 +1,112 @@
+use crate::api::errors::ApiError;
+use crate::api::openapi::ApiDoc;
+use crate::telemetry;
+use axum::middleware;
+use axum::routing::get;
+use axum::{Json, Router};
+use metrics_exporter_prometheus::PrometheusHandle;
+use model::campaign::Campaign;
+use sqlx::PgPool;
+use std::future::IntoFuture;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use utoipa::OpenApi;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub mod applicant;
+pub mod application;
+pub mod errors;
+pub mod etag;
+pub mod health;
+pub mod offer;
+pub mod openapi;
+pub mod pagination;
+pub mod ranking;
+
+// Read-only JSON API over `common` + `scraped`. Until `placement` exists, cutoffs and
+// recommendations are EDBO's published ones, not our own allocation.
+#[derive(Debug, Clone)]
+pub struct ApiState {
+    pub pool: PgPool,
+    pub campaign: Campaign,
+    pub freshness_max_age: Duration,
+}
+
+pub struct Api {
+    state: ApiState,
+    metrics: PrometheusHandle,
+}
+
+impl Api {
+    pub fn new(
+        pool: &PgPool, campaign: Campaign, freshness_max_age: Duration,
+        metrics: PrometheusHandle,
+    ) -> Self {
+        Self {
+            state: ApiState {
+                pool: pool.clone(),
+                campaign,
+                freshness_max_age,
+            },
+            metrics,
+        }
+    }
+
+    // `/metrics` is added after the latency layer, Prometheus' own scrapes aren't counted
+    pub fn router(&self) -> Router {
+        let (router, document) = Self::routes().split_for_parts();
+        let metrics = self.metrics.clone();
+
+        router
+            .route(openapi::PATH, get(|| async move { Json(document) }))
+            .route_layer(middleware::from_fn(telemetry::track_api))
+            .route(telemetry::PATH, get(|| async move { metrics.render() }))
+            .with_state(self.state.clone())
+    }
+
+    fn routes() -> OpenApiRouter<ApiState> {
+        OpenApiRouter::with_openapi(ApiDoc::openapi())
+            .routes(routes!(offer::list))
+            .routes(routes!(offer::get))
+            .routes(routes!(offer::applications))
+            .routes(routes!(ranking::speciality))
+            .routes(routes!(applicant::get))
+            .routes(routes!(health::healthz))
+            .routes(routes!(health::readyz))
+            .routes(routes!(health::freshness))
+    }
+
+    // On shutdown, stops accepting and lets open requests finish for up to
+    // `drain_timeout`. Whatever is still open after that is dropped.
+    pub async fn serve(
+        &self, address: SocketAddr, shutdown: CancellationToken, drain_timeout: Duration,
+    ) -> Result<(), ApiError> {
+        let listener = tokio::net::TcpListener::bind(address)
+            .await
+            .map_err(|error| ApiError::Bind(address, error))?;
+        log::info!("API listening on {address}.");
+
+        let serving = axum::serve(listener, self.router())
+            .with_graceful_shutdown(shutdown.clone().cancelled_owned())
+            .into_future();
+        let deadline = async {
+            shutdown.cancelled().await;
+            tokio::time::sleep(drain_timeout).await;
+        };
+
+        tokio::select! {
+            served = serving => served.map_err(ApiError::Serve)?,
+            () = deadline => {
+                log::warn!(
+                    "Connections still open after {}s, closing them.",
+                    drain_timeout.as_secs()
+                );
+            },
+        }
+
+        log::info!("API stopped.");
+        Ok(())
+    }
+}
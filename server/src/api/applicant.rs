@@ -0,0 +1,72 @@
+use crate::api::ApiState;
+use crate::api::application::{self, ApplicationResponse, ApplicationRow};
+use crate::api::errors::{ApiError, ErrorBody};
+use crate::api::etag;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApplicantResponse {
+    pub id: i32,
+    // Every application with its current outcome, best priority first
+    pub applications: Vec<ApplicationResponse>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/applicants/{id}",
+    operation_id = "get_applicant",
+    tag = "applicants",
+    description = "The outcome of every application of one applicant.",
+    params(("id" = i32, Path, description = "Applicant id")),
+    responses(
+        (status = 200, body = ApplicantResponse),
+        (status = 304, description = "Matches `If-None-Match`."),
+        (status = 404, body = ErrorBody),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn get(
+    State(state): State<ApiState>, Path(id): Path<i32>, headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    const QUERY: &str = "applicant";
+
+    let campaign = i16::from(state.campaign);
+
+    let applicant = sqlx::query!(
+        r#"SELECT id AS "id!" FROM api.applicant
+           WHERE campaign_year = $1 AND id = $2"#,
+        campaign,
+        id,
+    )
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?
+    .ok_or_else(|| ApiError::NotFound("Applicant", id.to_string()))?;
+
+    let rows = sqlx::query_as!(
+        ApplicationRow,
+        r#"SELECT a.number_in_list::INT8 AS "rank!",
+                  a.offer_id AS "offer_id!", a.number_in_list AS "number_in_list!",
+                  a.user_id AS "applicant_id!", a.grade AS "grade!",
+                  a.priority_code AS "priority_code!", a.status_id AS "status_id!"
+           FROM api.application a
+           WHERE a.campaign_year = $1 AND a.user_id = $2
+           ORDER BY a.priority_code = 0, a.priority_code, a.offer_id"#,
+        campaign,
+        id,
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let response = ApplicantResponse {
+        id: applicant.id,
+        applications: application::into_responses(rows)?,
+    };
+
+    etag::json(&headers, &response)
+}
@@ -0,0 +1,87 @@
+use crate::api::errors::ApiError;
+use bigdecimal::BigDecimal;
+use model::application::priority::Priority;
+use model::application::status::ApplicationStatus;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+use utoipa::ToSchema;
+
+// Shape shared by every query returning applications. They read `api` views, whose
+// columns sqlx sees as nullable: `AS "column!"` restores the tables' NOT NULL.
+#[derive(Debug)]
+pub struct ApplicationRow {
+    pub rank: i64,
+    pub offer_id: i32,
+    pub number_in_list: i32,
+    pub applicant_id: i32,
+    pub grade: BigDecimal,
+    pub priority_code: i16,
+    pub status_id: i16,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApplicationResponse {
+    pub rank: i64,
+    pub offer_id: i32,
+    pub number_in_list: i32,
+    // Our own applicant id from identity resolution (for 2025, `edbo_core`'s), not EDBO's.
+    // It's the only way applicants are identified: names stay in `scraped`.
+    pub applicant_id: i32,
+    #[schema(value_type = String)]
+    pub grade: BigDecimal,
+    pub priority: String,
+    pub status_id: i16,
+    pub status: String,
+    // Recommended for a budget place, the same set the offer's cutoff is taken from
+    pub is_recommended: bool,
+}
+
+impl TryFrom<ApplicationRow> for ApplicationResponse {
+    type Error = ApiError;
+
+    fn try_from(row: ApplicationRow) -> Result<Self, Self::Error> {
+        let priority = Priority::try_from(row.priority_code)
+            .map_err(|error| ApiError::InvalidData("priority", error.to_string()))?;
+        let status = ApplicationStatus::try_from(row.status_id)
+            .map_err(|error| ApiError::InvalidData("status", error.to_string()))?;
+
+        Ok(Self {
+            rank: row.rank,
+            offer_id: row.offer_id,
+            number_in_list: row.number_in_list,
+            applicant_id: row.applicant_id,
+            grade: row.grade,
+            priority: priority.to_string(),
+            status_id: row.status_id,
+            status: status.to_string(),
+            is_recommended: matches!(priority, Priority::Budget(_))
+                && BUDGET_RECOMMENDED.contains(&status),
+        })
+    }
+}
+
+pub fn into_responses(
+    rows: Vec<ApplicationRow>,
+) -> Result<Vec<ApplicationResponse>, ApiError> {
+    rows.into_iter()
+        .map(ApplicationResponse::try_from)
+        .collect()
+}
+
+// EDBO's own budget recommendations, standing in for placement results. Behind both
+// `is_recommended` and the cutoff, so a response can't disagree with itself.
+const BUDGET_RECOMMENDED: [ApplicationStatus; 2] = [
+    ApplicationStatus::RecommendedBudget,
+    ApplicationStatus::ToEnrollmentOrder,
+];
+
+pub fn budget_recommended_statuses() -> Vec<i16> {
+    BUDGET_RECOMMENDED.into_iter().map(i16::from).collect()
+}
+
+pub fn budget_competing_statuses() -> Vec<i16> {
+    ApplicationStatus::iter()
+        .filter(|status| status.competes_for_budget())
+        .map(i16::from)
+        .collect()
+}
@@ -0,0 +1,51 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::net::SocketAddr;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+#[derive(Debug, Error)]
+pub enum ApiError {
+    // Server
+    #[error("Failed to bind {0}. {1}")]
+    Bind(SocketAddr, std::io::Error),
+
+    #[error("Server stopped. {0}")]
+    Serve(std::io::Error),
+
+    // Requests
+    #[error("{0} \"{1}\" not found.")]
+    NotFound(&'static str, String),
+
+    #[error("Query \"{0}\". {1}")]
+    Query(&'static str, sqlx::Error),
+
+    #[error("Stored {0} is invalid. {1}")]
+    InvalidData(&'static str, String),
+
+    #[error("Response serialization. {0}")]
+    Serialization(serde_json::Error),
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = if let Self::NotFound(..) = self {
+            (StatusCode::NOT_FOUND, self.to_string())
+        } else {
+            log::error!("API. {self}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                String::from("Internal server error."),
+            )
+        };
+
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
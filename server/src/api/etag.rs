@@ -0,0 +1,44 @@
+use crate::api::errors::ApiError;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// Data only changes when the scraper runs, so clients poll with `If-None-Match` and
+// mostly get an empty 304. Weak, because it's a hash of our JSON, not of stored bytes.
+pub fn json<T: Serialize>(headers: &HeaderMap, body: &T) -> Result<Response, ApiError> {
+    let bytes = serde_json::to_vec(body).map_err(ApiError::Serialization)?;
+
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    let etag = format!("W/\"{:016x}\"", hasher.finish());
+
+    let is_fresh = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|tag| tag == etag || tag == "*")
+        });
+
+    if is_fresh {
+        return Ok((
+            StatusCode::NOT_MODIFIED,
+            [(ETAG, etag), (CACHE_CONTROL, String::from("no-cache"))],
+        )
+            .into_response());
+    }
+
+    Ok((
+        [
+            (CONTENT_TYPE, String::from("application/json")),
+            (ETAG, etag),
+            (CACHE_CONTROL, String::from("no-cache")),
+        ],
+        bytes,
+    )
+        .into_response())
+}
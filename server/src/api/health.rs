@@ -0,0 +1,185 @@
+use crate::api::ApiState;
+use crate::api::errors::{ApiError, ErrorBody};
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{SecondsFormat, Utc};
+use scraper::Scraper;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+// Failed checks answer 503, so orchestrators and uptime checks only read the status
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    pub checks: Vec<Check>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Check {
+    pub name: &'static str,
+    pub ok: bool,
+    // Why it failed, none when it passed
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FreshnessResponse {
+    pub campaign: i16,
+    pub max_age_seconds: u64,
+    pub fresh: bool,
+    pub entities: Vec<EntityFreshness>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EntityFreshness {
+    pub entity: String,
+    // RFC 3339, none when it was never refreshed for this campaign
+    pub refreshed_at: Option<String>,
+    pub age_seconds: Option<i64>,
+    pub fresh: bool,
+}
+
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    operation_id = "health",
+    tag = "health",
+    description = "The process is up and serving. Checks nothing else.",
+    responses((status = 200, description = "Alive."))
+)]
+pub async fn healthz() -> StatusCode {
+    StatusCode::OK
+}
+
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    operation_id = "readiness",
+    tag = "health",
+    description = "The database is reachable, migrations are applied and the campaign has \
+        data: a refresh from EDBO or imported offers.",
+    responses(
+        (status = 200, body = ReadinessResponse),
+        (status = 503, body = ReadinessResponse),
+    )
+)]
+pub async fn readyz(State(state): State<ApiState>) -> Response {
+    let database = sqlx::query("SELECT 1")
+        .execute(&state.pool)
+        .await
+        .map(|_| ())
+        .map_err(|error| error.to_string());
+
+    let migrations = Scraper::new(&state.pool, state.campaign)
+        .pending_migrations()
+        .await
+        .map_err(|error| error.to_string())
+        .and_then(|pending| match pending {
+            0 => Ok(()),
+            pending => Err(format!("{pending} pending.")),
+        });
+
+    // Stand-in until placement runs exist: something was loaded for the campaign. Offers
+    // aren't scraped yet, so for a live campaign that's a refresh from EDBO; a finished
+    // one only has the legacy import's offers.
+    let data = sqlx::query_scalar!(
+        "SELECT EXISTS (SELECT 1 FROM common.refresh WHERE campaign_year = $1)
+             OR EXISTS (SELECT 1 FROM common.offer WHERE campaign_year = $1)",
+        i16::from(state.campaign),
+    )
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|error| error.to_string())
+    .and_then(|exists| match exists {
+        Some(true) => Ok(()),
+        _ => Err(format!(
+            "Nothing refreshed or imported for campaign {} yet.",
+            state.campaign
+        )),
+    });
+
+    let checks = vec![
+        Check::new("database", database),
+        Check::new("migrations", migrations),
+        Check::new("data", data),
+    ];
+    let ready = checks.iter().all(|check| check.ok);
+
+    (status(ready), Json(ReadinessResponse { ready, checks })).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/freshness",
+    operation_id = "freshness",
+    tag = "health",
+    description = "When each scraped entity was last refreshed from EDBO. Stale when older \
+        than `freshness_max_age_seconds`, or never refreshed for the campaign.",
+    responses(
+        (status = 200, body = FreshnessResponse),
+        (status = 503, body = FreshnessResponse),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn freshness(State(state): State<ApiState>) -> Result<Response, ApiError> {
+    const QUERY: &str = "freshness";
+
+    let rows = sqlx::query!(
+        "SELECT entity, refreshed_at FROM common.refresh WHERE campaign_year = $1",
+        i16::from(state.campaign),
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let now = Utc::now();
+    let max_age = i64::try_from(state.freshness_max_age.as_secs()).unwrap_or(i64::MAX);
+    let entities: Vec<_> = scraper::REFRESHED_ENTITIES
+        .iter()
+        .map(|&entity| {
+            let refreshed_at = rows
+                .iter()
+                .find(|row| row.entity == entity)
+                .map(|row| row.refreshed_at);
+            let age_seconds =
+                refreshed_at.map(|refreshed_at| (now - refreshed_at).num_seconds());
+
+            EntityFreshness {
+                entity: entity.to_string(),
+                refreshed_at: refreshed_at
+                    .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                age_seconds,
+                fresh: age_seconds.is_some_and(|age| age <= max_age),
+            }
+        })
+        .collect();
+    let fresh = entities.iter().all(|entity| entity.fresh);
+
+    let body = FreshnessResponse {
+        campaign: i16::from(state.campaign),
+        max_age_seconds: state.freshness_max_age.as_secs(),
+        fresh,
+        entities,
+    };
+    Ok((status(fresh), Json(body)).into_response())
+}
+
+impl Check {
+    fn new(name: &'static str, outcome: Result<(), String>) -> Self {
+        Self {
+            name,
+            ok: outcome.is_ok(),
+            error: outcome.err(),
+        }
+    }
+}
+
+const fn status(ok: bool) -> StatusCode {
+    if ok {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
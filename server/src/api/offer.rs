@@ -0,0 +1,225 @@
+use crate::api::ApiState;
+use crate::api::application::{self, ApplicationResponse, ApplicationRow};
+use crate::api::errors::{ApiError, ErrorBody};
+use crate::api::etag;
+use crate::api::pagination::{PageQuery, Paginated};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+// `?region=80&institution=41&speciality=F2&study_form=1`, all optional
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OfferFilter {
+    pub region: Option<i16>,
+    pub institution: Option<i16>,
+    pub speciality: Option<String>,
+    pub study_form: Option<i16>,
+}
+
+// One offer at one institution
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OfferResponse {
+    pub id: i32,
+    pub title: String,
+    pub institution_id: i16,
+    pub institution: String,
+    pub region_id: Option<i16>,
+    pub speciality_code: String,
+    pub education_program: String,
+    pub faculty: Option<String>,
+    pub study_form_id: i16,
+    pub study_form: String,
+    pub license_volume: i32,
+    pub budgetary_places: i32,
+    // Lowest grade among budget recommendations, none before they're published
+    #[schema(value_type = Option<String>)]
+    pub cutoff: Option<BigDecimal>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/offers",
+    operation_id = "list_offers",
+    tag = "offers",
+    description = "Offers at institutions with seats and the current budget cutoff.",
+    params(OfferFilter, PageQuery),
+    responses(
+        (status = 200, body = Paginated<OfferResponse>),
+        (status = 304, description = "Matches `If-None-Match`."),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn list(
+    State(state): State<ApiState>, Query(filter): Query<OfferFilter>,
+    Query(page): Query<PageQuery>, headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    const QUERY: &str = "offers";
+
+    let recommended = application::budget_recommended_statuses();
+    let campaign = i16::from(state.campaign);
+
+    let offers = sqlx::query_as!(
+        OfferResponse,
+        r#"SELECT o.id, o.title, oi.university_id AS institution_id, i.name AS institution,
+                  i.region_id, o.speciality_code, o.education_program, o.faculty,
+                  o.study_form_id, sf.title AS study_form, o.license_volume,
+                  o.budgetary_places,
+                  (SELECT MIN(a.grade) FROM api.application a
+                   WHERE a.campaign_year = o.campaign_year AND a.offer_id = o.id
+                     AND a.priority_code > 0 AND a.status_id = ANY($6)) AS "cutoff?"
+           FROM common.offer o
+           JOIN common.offers_institutions oi
+             ON oi.campaign_year = o.campaign_year AND oi.offer_id = o.id
+           JOIN common.institution i ON i.id = oi.university_id
+           JOIN common.study_form sf ON sf.id = o.study_form_id
+           WHERE o.campaign_year = $1
+             AND ($2::INT2 IS NULL OR i.region_id = $2)
+             AND ($3::INT2 IS NULL OR oi.university_id = $3)
+             AND ($4::VARCHAR IS NULL OR o.speciality_code = $4)
+             AND ($5::INT2 IS NULL OR o.study_form_id = $5)
+           ORDER BY o.id, oi.university_id
+           LIMIT $7 OFFSET $8"#,
+        campaign,
+        filter.region,
+        filter.institution,
+        filter.speciality,
+        filter.study_form,
+        &recommended,
+        page.limit(),
+        page.offset(),
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "total!"
+           FROM common.offer o
+           JOIN common.offers_institutions oi
+             ON oi.campaign_year = o.campaign_year AND oi.offer_id = o.id
+           JOIN common.institution i ON i.id = oi.university_id
+           WHERE o.campaign_year = $1
+             AND ($2::INT2 IS NULL OR i.region_id = $2)
+             AND ($3::INT2 IS NULL OR oi.university_id = $3)
+             AND ($4::VARCHAR IS NULL OR o.speciality_code = $4)
+             AND ($5::INT2 IS NULL OR o.study_form_id = $5)"#,
+        campaign,
+        filter.region,
+        filter.institution,
+        filter.speciality,
+        filter.study_form,
+    )
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    etag::json(&headers, &page.wrap(offers, total))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/offers/{id}",
+    operation_id = "get_offer",
+    tag = "offers",
+    description = "One offer, once per institution it's held at.",
+    params(("id" = i32, Path, description = "EDBO offer id")),
+    responses(
+        (status = 200, body = Vec<OfferResponse>),
+        (status = 304, description = "Matches `If-None-Match`."),
+        (status = 404, body = ErrorBody),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn get(
+    State(state): State<ApiState>, Path(id): Path<i32>, headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let recommended = application::budget_recommended_statuses();
+
+    let offers = sqlx::query_as!(
+        OfferResponse,
+        r#"SELECT o.id, o.title, oi.university_id AS institution_id, i.name AS institution,
+                  i.region_id, o.speciality_code, o.education_program, o.faculty,
+                  o.study_form_id, sf.title AS study_form, o.license_volume,
+                  o.budgetary_places,
+                  (SELECT MIN(a.grade) FROM api.application a
+                   WHERE a.campaign_year = o.campaign_year AND a.offer_id = o.id
+                     AND a.priority_code > 0 AND a.status_id = ANY($3)) AS "cutoff?"
+           FROM common.offer o
+           JOIN common.offers_institutions oi
+             ON oi.campaign_year = o.campaign_year AND oi.offer_id = o.id
+           JOIN common.institution i ON i.id = oi.university_id
+           JOIN common.study_form sf ON sf.id = o.study_form_id
+           WHERE o.campaign_year = $1 AND o.id = $2
+           ORDER BY oi.university_id"#,
+        i16::from(state.campaign),
+        id,
+        &recommended,
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query("offer", error))?;
+
+    if offers.is_empty() {
+        return Err(ApiError::NotFound("Offer", id.to_string()));
+    }
+
+    etag::json(&headers, &offers)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/offers/{id}/applications",
+    operation_id = "list_offer_applications",
+    tag = "offers",
+    description = "EDBO's ranked list of one offer, in published order.",
+    params(("id" = i32, Path, description = "EDBO offer id"), PageQuery),
+    responses(
+        (status = 200, body = Paginated<ApplicationResponse>),
+        (status = 304, description = "Matches `If-None-Match`."),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn applications(
+    State(state): State<ApiState>, Path(id): Path<i32>, Query(page): Query<PageQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    const QUERY: &str = "offer applications";
+
+    let campaign = i16::from(state.campaign);
+
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "total!" FROM api.application
+           WHERE campaign_year = $1 AND offer_id = $2"#,
+        campaign,
+        id,
+    )
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let rows = sqlx::query_as!(
+        ApplicationRow,
+        r#"SELECT ROW_NUMBER() OVER (ORDER BY a.number_in_list) AS "rank!",
+                  a.offer_id AS "offer_id!", a.number_in_list AS "number_in_list!",
+                  a.user_id AS "applicant_id!", a.grade AS "grade!",
+                  a.priority_code AS "priority_code!", a.status_id AS "status_id!"
+           FROM api.application a
+           WHERE a.campaign_year = $1 AND a.offer_id = $2
+           ORDER BY a.number_in_list
+           LIMIT $3 OFFSET $4"#,
+        campaign,
+        id,
+        page.limit(),
+        page.offset(),
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let items = application::into_responses(rows)?;
+    etag::json(&headers, &page.wrap(items, total))
+}
@@ -0,0 +1,46 @@
+use utoipa::OpenApi;
+
+// Served as JSON; clients are generated from it
+pub const PATH: &str = "/openapi.json";
+
+// Paths and schemas are added by `Api::routes`, from the same `routes!` that build the
+// router, so a handler can't be served without being documented.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "abit-rs",
+        description = "Master's budget admission: offers, rankings and outcomes."
+    ),
+    tags(
+        (name = "offers", description = "Offers with seats, cutoffs and ranked lists"),
+        (name = "rankings", description = "Speciality-wide competition"),
+        (name = "applicants", description = "Per-applicant outcomes"),
+        (name = "health", description = "Liveness, readiness and data freshness"),
+    )
+)]
+pub struct ApiDoc;
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Api;
+    use std::path::PathBuf;
+
+    // `docs/openapi.json` is what clients are generated from. When this fails after an
+    // intended API change, regenerate it: `UPDATE_OPENAPI=1 cargo test -p server openapi`
+    #[test]
+    fn committed_spec_matches_handlers() -> Result<(), Box<dyn std::error::Error>> {
+        let generated = Api::routes().into_openapi().to_pretty_json()? + "\n";
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../docs/openapi.json");
+
+        if std::env::var_os("UPDATE_OPENAPI").is_some() {
+            std::fs::write(&path, &generated)?;
+        }
+
+        let committed = std::fs::read_to_string(&path)?;
+        assert!(
+            committed == generated,
+            "docs/openapi.json is out of date with the handlers"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::api::ApiState;
+use crate::api::application::{self, ApplicationResponse, ApplicationRow};
+use crate::api::errors::{ApiError, ErrorBody};
+use crate::api::etag;
+use crate::api::pagination::{PageQuery, Paginated};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use model::speciality::Speciality;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RankingFilter {
+    pub study_form: Option<i16>,
+}
+
+// Wide competition: master's budget places are contested per speciality across every
+// institution, so this ranks all budget applications still in the race together.
+#[utoipa::path(
+    get,
+    path = "/api/v1/specialities/{code}/ranking",
+    operation_id = "get_speciality_ranking",
+    tag = "rankings",
+    description = "Budget applications still competing for a speciality, across institutions.",
+    params(
+        ("code" = String, Path, description = "Speciality code, e.g. F2"),
+        RankingFilter,
+        PageQuery,
+    ),
+    responses(
+        (status = 200, body = Paginated<ApplicationResponse>),
+        (status = 304, description = "Matches `If-None-Match`."),
+        (status = 404, body = ErrorBody),
+        (status = 500, body = ErrorBody),
+    )
+)]
+pub async fn speciality(
+    State(state): State<ApiState>, Path(code): Path<String>,
+    Query(filter): Query<RankingFilter>, Query(page): Query<PageQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    const QUERY: &str = "speciality ranking";
+
+    let speciality = Speciality::from_code(&code)
+        .ok_or_else(|| ApiError::NotFound("Speciality", code.clone()))?;
+    let competing = application::budget_competing_statuses();
+    let campaign = i16::from(state.campaign);
+
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "total!"
+           FROM api.application a
+           JOIN common.offer o ON o.campaign_year = a.campaign_year AND o.id = a.offer_id
+           WHERE a.campaign_year = $1 AND o.speciality_code = $2
+             AND ($3::INT2 IS NULL OR o.study_form_id = $3)
+             AND a.priority_code > 0 AND a.status_id = ANY($4)"#,
+        campaign,
+        speciality.code(),
+        filter.study_form,
+        &competing,
+    )
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let rows = sqlx::query_as!(
+        ApplicationRow,
+        r#"SELECT ROW_NUMBER() OVER (ORDER BY a.grade DESC, a.priority_code, a.user_id)
+                    AS "rank!",
+                  a.offer_id AS "offer_id!", a.number_in_list AS "number_in_list!",
+                  a.user_id AS "applicant_id!", a.grade AS "grade!",
+                  a.priority_code AS "priority_code!", a.status_id AS "status_id!"
+           FROM api.application a
+           JOIN common.offer o ON o.campaign_year = a.campaign_year AND o.id = a.offer_id
+           WHERE a.campaign_year = $1 AND o.speciality_code = $2
+             AND ($3::INT2 IS NULL OR o.study_form_id = $3)
+             AND a.priority_code > 0 AND a.status_id = ANY($4)
+           ORDER BY 1
+           LIMIT $5 OFFSET $6"#,
+        campaign,
+        speciality.code(),
+        filter.study_form,
+        &competing,
+        page.limit(),
+        page.offset(),
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|error| ApiError::Query(QUERY, error))?;
+
+    let items = application::into_responses(rows)?;
+    etag::json(&headers, &page.wrap(items, total))
+}
@@ -0,0 +1,360 @@
+use crate::api::Api;
+use crate::config::Config;
+use crate::database::Database;
+use crate::errors::ServerError;
+use crate::logs::{LogFormat, LogLevel, Logger};
+use crate::scheduler::Scheduler;
+use crate::scheduler::errors::SchedulerError;
+use crate::scheduler::lock::ScrapeLock;
+use crate::settings::{RuntimeSettings, RuntimeSettingsError};
+use crate::shutdown;
+use crate::telemetry;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use metrics_exporter_prometheus::PrometheusHandle;
+use scraper::Scraper;
+use sqlx::PgPool;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+
+// Every stage can run on its own, from cron or a script. Without a subcommand the
+// binary does what it always did: migrate, import legacy if configured and not done yet,
+// scrape, serve.
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about = "Master's budget admission: scraping, placement and API."
+)]
+pub struct Cli {
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Config file. Default: config.toml next to the executable, if present."
+    )]
+    config: Option<PathBuf>,
+
+    #[command(flatten)]
+    overrides: Overrides,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+// The last configuration layer, over the file and `ABIT_*` variables
+#[derive(Debug, Args)]
+struct Overrides {
+    #[arg(long, global = true)]
+    database_url: Option<String>,
+
+    #[arg(long, global = true)]
+    campaign: Option<i16>,
+
+    #[arg(long, global = true)]
+    listen_address: Option<String>,
+
+    #[arg(long, global = true, value_name = "CRON")]
+    schedule: Option<String>,
+
+    #[arg(long, global = true)]
+    log_level: Option<LogLevel>,
+
+    #[arg(long, global = true, help = "Text or Json.")]
+    log_format: Option<LogFormat>,
+
+    #[arg(long, global = true, value_name = "PATH")]
+    log_directory: Option<PathBuf>,
+}
+
+impl From<Overrides> for Config {
+    fn from(overrides: Overrides) -> Self {
+        Self {
+            database_url: overrides.database_url,
+            campaign: overrides.campaign,
+            listen_address: overrides.listen_address,
+            schedule: overrides.schedule,
+            log_level: overrides.log_level,
+            log_format: overrides.log_format,
+            log_directory: overrides.log_directory,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(about = "Apply pending database migrations.")]
+    Migrate,
+
+    #[command(about = "Fetch from EDBO into the database.")]
+    Scrape {
+        #[arg(long, value_enum, help = "Only this entity. Default: all of them.")]
+        entity: Option<Entity>,
+
+        #[arg(long, help = "Fetch again even if already stored.")]
+        refresh: bool,
+    },
+
+    #[command(about = "Import last year's campaign from `legacy_database_url`.")]
+    ImportLegacy,
+
+    #[command(about = "Run placement over scraped applications.")]
+    Place {
+        #[arg(long, help = "Snapshot to place. Default: the latest one.")]
+        snapshot: Option<i32>,
+    },
+
+    #[command(about = "Serve the read-only HTTP API.")]
+    Serve,
+
+    #[command(about = "Compare two snapshots.")]
+    Diff { a: i32, b: i32 },
+
+    #[command(about = "Export placement results.")]
+    Export,
+
+    #[command(about = "Validate the configuration and print the resulting settings.")]
+    CheckConfig,
+}
+
+impl Command {
+    // Stages whose building blocks don't exist yet: no placement, no snapshots, and
+    // offers and applications aren't scraped.
+    const fn unsupported(&self) -> Option<&'static str> {
+        match self {
+            Self::Scrape {
+                entity: Some(Entity::Offer),
+                ..
+            } => Some("Scraping offers"),
+            Self::Scrape {
+                entity: Some(Entity::Application),
+                ..
+            } => Some("Scraping applications"),
+            Self::Place { .. } => Some("Placement"),
+            Self::Diff { .. } => Some("Snapshot diff"),
+            Self::Export => Some("Export"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Entity {
+    Institution,
+    Offer,
+    Application,
+}
+
+impl Cli {
+    pub async fn run(self) -> Result<(), ServerError> {
+        let config = Config::load(self.config.as_deref(), self.overrides.into())?;
+        let settings = RuntimeSettings::try_from(config)?;
+
+        let Some(command) = self.command else {
+            return Self::run_all(&settings).await;
+        };
+
+        if let Some(stage) = command.unsupported() {
+            return Err(ServerError::Unsupported(stage));
+        }
+
+        if matches!(command, Command::CheckConfig) {
+            println!("Configuration is valid. {settings:#?}");
+            return Ok(());
+        }
+
+        Self::setup_logger(&settings)?;
+        let shutdown = shutdown::listen();
+
+        // Needs no owner: it only reads, through roles that can't migrate
+        if matches!(command, Command::Serve) {
+            return Self::serve(&settings, None, telemetry::install()?, shutdown).await;
+        }
+
+        if matches!(command, Command::Migrate) {
+            Self::migrated(&settings).await?;
+            return Ok(());
+        }
+
+        // `scrape` and `import-legacy` run from cron next to a server: they connect as the
+        // scraper and refuse an outdated schema instead of migrating it
+        let pool = Self::scraper_pool(&settings, None).await?;
+        let scraper =
+            Scraper::new(&pool, settings.campaign).with_shutdown(shutdown.clone());
+        scraper.ensure_migrated().await?;
+
+        match command {
+            Command::Scrape { refresh, .. } => {
+                Self::scrape(&pool, &scraper, refresh).await?;
+            },
+            Command::ImportLegacy => {
+                Self::import_legacy(&scraper, &settings, true).await?;
+            },
+            // Handled or rejected before connecting
+            Command::Migrate
+            | Command::Serve
+            | Command::Place { .. }
+            | Command::Diff { .. }
+            | Command::Export
+            | Command::CheckConfig => {},
+        }
+
+        Ok(())
+    }
+
+    async fn run_all(settings: &RuntimeSettings) -> Result<(), ServerError> {
+        Self::setup_logger(settings)?;
+        // Before the first scrape, so it shows up in `/metrics` once serving
+        let metrics = telemetry::install()?;
+        let shutdown = shutdown::listen();
+
+        let pool = Self::migrated(settings).await?;
+        let scraper =
+            Scraper::new(&pool, settings.campaign).with_shutdown(shutdown.clone());
+
+        if settings.legacy_database_url.is_some() {
+            Self::import_legacy(&scraper, settings, false).await?;
+        }
+
+        log::info!("Starting process...");
+        // Another replica is already scraping the same database, serving is what's left
+        match Self::scrape(&pool, &scraper, false).await {
+            Err(ServerError::Scheduler(SchedulerError::Busy)) => {
+                log::info!("Another instance is scraping, skipping straight to serving.");
+            },
+            outcome => outcome?,
+        }
+
+        Self::serve(settings, Some(&pool), metrics, shutdown).await
+    }
+
+    // Only the owner changes the schema. Returns the scraper's pool, for everything after.
+    async fn migrated(settings: &RuntimeSettings) -> Result<PgPool, ServerError> {
+        let url = settings
+            .database_url
+            .as_ref()
+            .ok_or(RuntimeSettingsError::DatabaseUrlEmpty)?;
+        let owner = Database::owner(url).await?;
+        log::info!("Database successfully initialized.");
+        Scraper::new(&owner, settings.campaign).migrate().await?;
+
+        Self::scraper_pool(settings, Some(&owner)).await
+    }
+
+    // Without its own URL the scraper connects as the owner, with every privilege
+    async fn scraper_pool(
+        settings: &RuntimeSettings, owner: Option<&PgPool>,
+    ) -> Result<PgPool, ServerError> {
+        if let Some(url) = &settings.scraper_database_url {
+            log::info!("Connecting the scraper with its own role...");
+            return Ok(Database::connect(url.expose()).await?);
+        }
+
+        log::warn!("scraper_database_url isn't set, the scraper connects as the owner.");
+        if let Some(owner) = owner {
+            return Ok(owner.clone());
+        }
+        let url = settings
+            .database_url
+            .as_ref()
+            .ok_or(RuntimeSettingsError::ScraperDatabaseUrlEmpty)?;
+        Ok(Database::connect(url.expose()).await?)
+    }
+
+    fn setup_logger(settings: &RuntimeSettings) -> Result<(), ServerError> {
+        Logger::from_settings(settings).setup()?;
+
+        log::info!("Configuration successfully loaded.");
+        log::info!("Runtime settings: {settings:?}");
+        log::info!("Logger successfully initialized.");
+
+        Ok(())
+    }
+
+    // Fails instead of waiting when a scheduled cycle or another instance is scraping
+    async fn scrape(
+        pool: &PgPool, scraper: &Scraper, refresh: bool,
+    ) -> Result<(), ServerError> {
+        let lock = ScrapeLock::try_acquire(pool)
+            .await?
+            .ok_or(SchedulerError::Busy)?;
+
+        // Released either way; a failed scrape is the error worth reporting, not a
+        // failed release after it
+        let outcome = scraper.scrape_institutions(refresh).await;
+        let released = lock.release().await;
+        outcome?;
+        released?;
+
+        Ok(())
+    }
+
+    async fn import_legacy(
+        scraper: &Scraper, settings: &RuntimeSettings, again: bool,
+    ) -> Result<(), ServerError> {
+        let url = settings
+            .legacy_database_url
+            .as_ref()
+            .ok_or(RuntimeSettingsError::LegacyDatabaseUrlEmpty)?;
+
+        log::info!("Importing legacy campaign...");
+        let legacy = Database::connect(url.expose()).await?;
+        scraper.import_legacy(&legacy, again).await?;
+
+        Ok(())
+    }
+
+    // With `schedule` configured, cycles run next to the API. On shutdown both wind down
+    // on their own; if either fails, the other is dropped. `scraper` is reused when the
+    // caller already has it.
+    async fn serve(
+        settings: &RuntimeSettings, scraper: Option<&PgPool>, metrics: PrometheusHandle,
+        shutdown: CancellationToken,
+    ) -> Result<(), ServerError> {
+        let url = settings
+            .api_database_url
+            .as_ref()
+            .ok_or(RuntimeSettingsError::ApiDatabaseUrlEmpty)?;
+        let pool = Database::api(url).await?;
+        Scraper::new(&pool, settings.campaign)
+            .ensure_migrated()
+            .await?;
+
+        let api = Api::new(
+            &pool,
+            settings.campaign,
+            settings.freshness_max_age,
+            metrics,
+        );
+        // Boxed: both are held across the join, too large for the caller's stack
+        let serving = Box::pin(async {
+            api.serve(
+                settings.listen_address,
+                shutdown.clone(),
+                settings.shutdown_timeout,
+            )
+            .await
+            .map_err(ServerError::from)
+        });
+
+        let Some(schedule) = &settings.schedule else {
+            serving.await?;
+            return Ok(());
+        };
+
+        let scraper = match scraper {
+            Some(pool) => pool.clone(),
+            None => Self::scraper_pool(settings, None).await?,
+        };
+        let scheduler = Scheduler::new(
+            &scraper,
+            settings.campaign,
+            schedule.clone(),
+            shutdown.clone(),
+        );
+        let scheduling =
+            Box::pin(async { scheduler.run().await.map_err(ServerError::from) });
+        tokio::try_join!(serving, scheduling)?;
+
+        Ok(())
+    }
+}
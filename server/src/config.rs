@@ -1,72 +1,266 @@
 use crate::logs;
-use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use thiserror::Error;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+// One layer of configuration. Layers are merged in order — defaults, file, `ABIT_*`
+// environment variables, CLI overrides — a later one replacing what an earlier one set.
+// Defaults for what's left unset live in `RuntimeSettings`, which validates the result.
+#[derive(Debug, Default, Clone, Deserialize)]
 pub struct Config {
-    pub database_url: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_url: Option<String>,
+    // Secrets can come from a file instead (Docker secrets): `*_file` here, `ABIT_*_FILE`
+    // in the environment. A layer setting either form replaces both from lower layers.
+    pub database_url_file: Option<PathBuf>,
+    pub legacy_database_url: Option<String>,
+    pub legacy_database_url_file: Option<PathBuf>,
+    // Per-component roles, least privilege. Unset, the component uses `database_url`.
+    pub scraper_database_url: Option<String>,
+    pub scraper_database_url_file: Option<PathBuf>,
+    pub api_database_url: Option<String>,
+    pub api_database_url_file: Option<PathBuf>,
+    pub campaign: Option<i16>,
+    pub listen_address: Option<String>,
+    pub schedule: Option<String>,
+    pub schedule_jitter_seconds: Option<u64>,
+    pub freshness_max_age_seconds: Option<u64>,
+    pub shutdown_timeout_seconds: Option<u64>,
     pub log_level: Option<logs::LogLevel>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_format: Option<logs::LogFormat>,
     pub log_directory: Option<PathBuf>,
+    pub log_retention_days: Option<u16>,
 }
 
 impl Config {
     const FILENAME: &str = "config.toml";
+    const ENV_PREFIX: &str = "ABIT_";
 
-    fn path() -> Result<PathBuf, ConfigError> {
+    // `path` is `--config`: it has to exist. Without it, `config.toml` next to the
+    // executable is read if it's there, and everything can come from the environment.
+    pub fn load(path: Option<&Path>, overrides: Self) -> Result<Self, ConfigError> {
+        let file = if let Some(path) = path {
+            Self::from_file(path)?
+        } else {
+            let path = Self::default_path()?;
+            if path.is_file() {
+                Self::from_file(&path)?
+            } else {
+                Self::default()
+            }
+        };
+
+        file.merge(Self::from_env()?)
+            .merge(overrides)
+            .read_secrets()
+    }
+
+    fn default_path() -> Result<PathBuf, ConfigError> {
         let mut current_dir = std::env::current_exe().map_err(ConfigError::IO)?;
         current_dir.pop(); // Remove executable name
 
-        std::fs::create_dir_all(&current_dir).map_err(ConfigError::IO)?;
-
         Ok(current_dir.join(Self::FILENAME))
     }
 
-    pub fn from_file() -> Result<Self, ConfigError> {
-        let path = Self::path()?;
-        let text = std::fs::read_to_string(&path);
-        let text = match text {
-            Ok(text) => text,
-            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
-                let config = Self::default();
-                config.save_to_file()?;
-                let error = ConfigError::FileNotFound(path);
-                return Err(error);
-            },
-            Err(error) => {
-                let error = ConfigError::from(error);
-                return Err(error);
+    fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|error| {
+            if error.kind() == std::io::ErrorKind::NotFound {
+                ConfigError::FileNotFound(path.to_path_buf())
+            } else {
+                ConfigError::IO(error)
+            }
+        })?;
+
+        toml::from_str(&text).map_err(ConfigError::Deserialization)
+    }
+
+    fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            database_url: Self::env("DATABASE_URL")?,
+            database_url_file: Self::env("DATABASE_URL_FILE")?,
+            legacy_database_url: Self::env("LEGACY_DATABASE_URL")?,
+            legacy_database_url_file: Self::env("LEGACY_DATABASE_URL_FILE")?,
+            scraper_database_url: Self::env("SCRAPER_DATABASE_URL")?,
+            scraper_database_url_file: Self::env("SCRAPER_DATABASE_URL_FILE")?,
+            api_database_url: Self::env("API_DATABASE_URL")?,
+            api_database_url_file: Self::env("API_DATABASE_URL_FILE")?,
+            campaign: Self::env("CAMPAIGN")?,
+            listen_address: Self::env("LISTEN_ADDRESS")?,
+            schedule: Self::env("SCHEDULE")?,
+            schedule_jitter_seconds: Self::env("SCHEDULE_JITTER_SECONDS")?,
+            freshness_max_age_seconds: Self::env("FRESHNESS_MAX_AGE_SECONDS")?,
+            shutdown_timeout_seconds: Self::env("SHUTDOWN_TIMEOUT_SECONDS")?,
+            log_level: Self::env("LOG_LEVEL")?,
+            log_format: Self::env("LOG_FORMAT")?,
+            log_directory: Self::env("LOG_DIRECTORY")?,
+            log_retention_days: Self::env("LOG_RETENTION_DAYS")?,
+        })
+    }
+
+    // Unset and empty are the same, so `ABIT_X=` can't be mistaken for a value
+    fn env<T: FromStr>(name: &str) -> Result<Option<T>, ConfigError> {
+        let name = format!("{}{name}", Self::ENV_PREFIX);
+
+        let value = match std::env::var(&name) {
+            Ok(value) if !value.is_empty() => value,
+            Ok(_) | Err(std::env::VarError::NotPresent) => return Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => {
+                return Err(ConfigError::NotUnicodeVariable(name));
             },
         };
-        let config = toml::from_str(&text).map_err(ConfigError::Deserialization)?;
-        Ok(config)
+
+        value
+            .parse()
+            .map(Some)
+            .map_err(|_| ConfigError::InvalidVariable(name, value))
+    }
+
+    fn merge(self, upper: Self) -> Self {
+        let (database_url, database_url_file) = Self::merge_secret(
+            (self.database_url, self.database_url_file),
+            (upper.database_url, upper.database_url_file),
+        );
+        let (legacy_database_url, legacy_database_url_file) = Self::merge_secret(
+            (self.legacy_database_url, self.legacy_database_url_file),
+            (upper.legacy_database_url, upper.legacy_database_url_file),
+        );
+        let (scraper_database_url, scraper_database_url_file) = Self::merge_secret(
+            (self.scraper_database_url, self.scraper_database_url_file),
+            (upper.scraper_database_url, upper.scraper_database_url_file),
+        );
+        let (api_database_url, api_database_url_file) = Self::merge_secret(
+            (self.api_database_url, self.api_database_url_file),
+            (upper.api_database_url, upper.api_database_url_file),
+        );
+
+        Self {
+            database_url,
+            database_url_file,
+            legacy_database_url,
+            legacy_database_url_file,
+            scraper_database_url,
+            scraper_database_url_file,
+            api_database_url,
+            api_database_url_file,
+            campaign: upper.campaign.or(self.campaign),
+            listen_address: upper.listen_address.or(self.listen_address),
+            schedule: upper.schedule.or(self.schedule),
+            schedule_jitter_seconds: upper
+                .schedule_jitter_seconds
+                .or(self.schedule_jitter_seconds),
+            freshness_max_age_seconds: upper
+                .freshness_max_age_seconds
+                .or(self.freshness_max_age_seconds),
+            shutdown_timeout_seconds: upper
+                .shutdown_timeout_seconds
+                .or(self.shutdown_timeout_seconds),
+            log_level: upper.log_level.or(self.log_level),
+            log_format: upper.log_format.or(self.log_format),
+            log_directory: upper.log_directory.or(self.log_directory),
+            log_retention_days: upper.log_retention_days.or(self.log_retention_days),
+        }
     }
 
-    pub fn save_to_file(&self) -> Result<(), ConfigError> {
-        let data = toml::to_string(&self).map_err(ConfigError::Serialization)?;
-        let path = Self::path()?;
+    // A value and a file are two forms of one setting: the upper layer wins as a pair
+    fn merge_secret(
+        lower: (Option<String>, Option<PathBuf>),
+        upper: (Option<String>, Option<PathBuf>),
+    ) -> (Option<String>, Option<PathBuf>) {
+        if upper.0.is_some() || upper.1.is_some() {
+            upper
+        } else {
+            lower
+        }
+    }
+
+    fn read_secrets(mut self) -> Result<Self, ConfigError> {
+        self.database_url = Self::read_secret(
+            "database_url",
+            self.database_url,
+            self.database_url_file.take(),
+        )?;
+        self.legacy_database_url = Self::read_secret(
+            "legacy_database_url",
+            self.legacy_database_url,
+            self.legacy_database_url_file.take(),
+        )?;
+        self.scraper_database_url = Self::read_secret(
+            "scraper_database_url",
+            self.scraper_database_url,
+            self.scraper_database_url_file.take(),
+        )?;
+        self.api_database_url = Self::read_secret(
+            "api_database_url",
+            self.api_database_url,
+            self.api_database_url_file.take(),
+        )?;
 
-        std::fs::write(path, data).map_err(ConfigError::IO)?;
+        Ok(self)
+    }
 
-        Ok(())
+    fn read_secret(
+        name: &'static str, value: Option<String>, file: Option<PathBuf>,
+    ) -> Result<Option<String>, ConfigError> {
+        match (value, file) {
+            (Some(_), Some(_)) => Err(ConfigError::SecretTwice(name)),
+            (value, None) => Ok(value),
+            (None, Some(path)) => {
+                let secret = std::fs::read_to_string(&path)
+                    .map_err(|error| ConfigError::SecretFile(path, error))?;
+                // Secret files usually end with a newline
+                Ok(Some(secret.trim_end().to_string()))
+            },
+        }
     }
 }
 
 #[derive(Debug, Error)]
 pub enum ConfigError {
-    #[error(
-        "File is not found by path \"{0}\". Utility created it for you there. Please, read documentation and fill desired configuration."
-    )]
+    #[error("File is not found by path \"{0}\".")]
     FileNotFound(PathBuf),
 
-    #[error("Failed to serialize. {0}")]
-    Serialization(#[from] toml::ser::Error),
-
     #[error("Failed to deserialize. {0}")]
     Deserialization(#[from] toml::de::Error),
 
+    #[error("Environment variable {0} is not valid unicode.")]
+    NotUnicodeVariable(String),
+
+    #[error("Environment variable {0} has an invalid value \"{1}\".")]
+    InvalidVariable(String, String),
+
+    #[error("Both {0} and {0}_file are set, only one can be.")]
+    SecretTwice(&'static str),
+
+    #[error("Failed to read secret file \"{0}\". {1}")]
+    SecretFile(PathBuf, std::io::Error),
+
     #[error("I/O. {0}")]
     IO(#[from] std::io::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use std::path::PathBuf;
+
+    #[test]
+    fn upper_layer_replaces_secret_in_either_form() {
+        let file = Config {
+            database_url: Some(String::from("postgres://file")),
+            campaign: Some(2025),
+            ..Config::default()
+        };
+        let env = Config {
+            database_url_file: Some(PathBuf::from("/run/secrets/database_url")),
+            ..Config::default()
+        };
+
+        let merged = file.merge(env);
+        assert_eq!(merged.database_url, None);
+        assert_eq!(
+            merged.database_url_file,
+            Some(PathBuf::from("/run/secrets/database_url"))
+        );
+        assert_eq!(merged.campaign, Some(2025));
+    }
+}
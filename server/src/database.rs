@@ -1,19 +1,19 @@
-use crate::settings::RuntimeSettings;
+use crate::settings::Secret;
 use sqlx::migrate::MigrateDatabase;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::{PgPool, Postgres};
 use thiserror::Error;
 
+// Each component connects as its own role (see migration 007). The owner is
+// `database_url`: it creates the database and runs migrations.
 #[derive(Debug)]
-pub struct Database {
-    pub pool: PgPool,
-}
+pub struct Database;
 
 const MAX_CONNECTIONS: u32 = 5;
 
 impl Database {
-    pub async fn init(settings: &RuntimeSettings) -> Result<Self, DbError> {
-        let url = &settings.database_url;
+    pub async fn owner(url: &Secret) -> Result<PgPool, DbError> {
+        let url = url.expose();
 
         let is_db_exists_at_initialization = Postgres::database_exists(url)
             .await
@@ -25,6 +25,10 @@ impl Database {
             Self::create_database(url).await?;
         }
 
+        Self::connect(url).await
+    }
+
+    pub async fn connect(url: &str) -> Result<PgPool, DbError> {
         let pool = PgPoolOptions::new()
             .max_connections(MAX_CONNECTIONS)
             .connect(url)
@@ -33,7 +37,41 @@ impl Database {
 
         log::info!("Database connection established.");
 
-        Ok(Self { pool })
+        Ok(pool)
+    }
+
+    // The only way to the API's pool, so it can't serve before the check passed
+    pub async fn api(url: &Secret) -> Result<PgPool, DbError> {
+        log::info!("Connecting the API with its own role...");
+        let pool = Self::connect(url.expose()).await?;
+        Self::verify_api_role(&pool).await?;
+
+        Ok(pool)
+    }
+
+    // The public API must not read `scraped`, everything it serves comes through `api`
+    // views. Asks Postgres what the API's role can select there, including through role
+    // membership, rather than trusting the grants in the migration: a superuser, the
+    // owner or a later manual GRANT would slip past those.
+    async fn verify_api_role(pool: &PgPool) -> Result<(), DbError> {
+        let readable = sqlx::query_scalar!(
+            r#"SELECT c.relname::TEXT AS "table!"
+               FROM pg_class c
+               JOIN pg_namespace n ON n.oid = c.relnamespace
+               WHERE n.nspname = 'scraped' AND c.relkind IN ('r', 'v', 'm', 'p', 'f')
+                 AND has_schema_privilege(n.oid, 'USAGE')
+                 AND has_any_column_privilege(c.oid, 'SELECT')"#
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(DbError::ApiRoleCheck)?;
+
+        if !readable.is_empty() {
+            return Err(DbError::ApiReadsScraped(readable.join(", ")));
+        }
+
+        log::info!("API role can't read `scraped`.");
+        Ok(())
     }
 
     async fn create_database(url: &str) -> Result<(), DbError> {
@@ -57,4 +95,10 @@ pub enum DbError {
 
     #[error("Failed to check is database exists or not. {0}")]
     ExistsOrNotValidation(sqlx::Error),
+
+    #[error("Failed to check the API role's privileges. {0}")]
+    ApiRoleCheck(sqlx::Error),
+
+    #[error("API role can read scraped tables: {0}. Grant it `abit_api` only.")]
+    ApiReadsScraped(String),
 }
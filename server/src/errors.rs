@@ -1,11 +1,19 @@
+use crate::api::errors::ApiError;
 use crate::config::ConfigError;
 use crate::database::DbError;
 use crate::logs::LogsError;
+use crate::scheduler::errors::SchedulerError;
 use crate::settings::RuntimeSettingsError;
+use crate::telemetry::TelemetryError;
+use scraper::{ErrorKind, ScraperError};
+use std::process::ExitCode;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ServerError {
+    #[error("API. {0}")]
+    Api(#[from] ApiError),
+
     #[error("Configuration. {0}")]
     Config(#[from] ConfigError),
 
@@ -17,4 +25,72 @@ pub enum ServerError {
 
     #[error("Settings. {0}")]
     RuntimeSettings(#[from] RuntimeSettingsError),
+
+    #[error("Scheduler. {0}")]
+    Scheduler(#[from] SchedulerError),
+
+    #[error("Scraper. {0}")]
+    Scraper(#[from] ScraperError),
+
+    #[error("Telemetry. {0}")]
+    Telemetry(#[from] TelemetryError),
+
+    #[error("{0} isn't implemented yet.")]
+    Unsupported(&'static str),
+}
+
+// Exit codes scripts and cron can branch on. 2 is clap's, for invalid arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ExitStatus {
+    Failure = 1,
+    Config = 3,
+    Database = 4,
+    Network = 5,
+    Data = 6,
+    // Another instance holds the scrape lock
+    Busy = 7,
+    // Stopped by SIGTERM or Ctrl+C before finishing, what wasn't committed is rolled back
+    Interrupted = 8,
+}
+
+impl ServerError {
+    pub fn exit_status(&self) -> ExitStatus {
+        match self {
+            Self::Config(_) | Self::Logs(_) | Self::RuntimeSettings(_) => {
+                ExitStatus::Config
+            },
+            // The database works, the API's role is set up wrong
+            Self::Database(DbError::ApiReadsScraped(_)) => ExitStatus::Config,
+            Self::Database(_) => ExitStatus::Database,
+            Self::Api(_) => ExitStatus::Network,
+            Self::Scheduler(error) => match error {
+                SchedulerError::NextOccurrence(_) => ExitStatus::Config,
+                SchedulerError::Busy => ExitStatus::Busy,
+                SchedulerError::Lock(_) | SchedulerError::History(..) => {
+                    ExitStatus::Database
+                },
+                SchedulerError::Scraper(error) => ExitStatus::from(error.kind()),
+            },
+            Self::Scraper(error) => ExitStatus::from(error.kind()),
+            Self::Telemetry(_) | Self::Unsupported(_) => ExitStatus::Failure,
+        }
+    }
+}
+
+impl From<ErrorKind> for ExitStatus {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Database => Self::Database,
+            ErrorKind::Network => Self::Network,
+            ErrorKind::Data => Self::Data,
+            ErrorKind::Cancelled => Self::Interrupted,
+        }
+    }
+}
+
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        Self::from(status as u8)
+    }
 }
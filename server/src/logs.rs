@@ -1,13 +1,21 @@
 use crate::settings::RuntimeSettings;
-use chrono::{Datelike, Local, Timelike};
+use chrono::{Local, NaiveDate};
 use log::LevelFilter;
 use o2o::o2o;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use strum_macros::{Display, EnumIter};
+use std::time::Duration;
+use strum_macros::{Display, EnumIter, EnumString};
 use thiserror::Error;
 
+pub mod context;
+pub mod format;
+
 pub const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
+pub const DEFAULT_LOG_RETENTION_DAYS: u16 = 30;
+
+// Daily files in a log directory: `2026-07-14.log`
+const FILE_DATE_FORMAT: &str = "%Y-%m-%d";
 
 #[derive(
     Debug,
@@ -15,6 +23,7 @@ pub const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
     Clone,
     Copy,
     EnumIter,
+    EnumString,
     Display,
     PartialEq,
     Eq,
@@ -23,6 +32,7 @@ pub const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
     o2o,
 )]
 #[o2o(map_owned(log::LevelFilter))]
+#[strum(ascii_case_insensitive)]
 pub enum LogLevel {
     #[default]
     Off,
@@ -33,6 +43,26 @@ pub enum LogLevel {
     Trace,
 }
 
+// `Json` writes one object per line, with context fields, for a log stack to ingest
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    EnumString,
+    Display,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub enum LogDestination {
     #[default]
@@ -41,66 +71,97 @@ pub enum LogDestination {
 }
 
 pub struct Logger {
-    log_level: LevelFilter,
-    log_destination: LogDestination,
+    level: LevelFilter,
+    format: LogFormat,
+    destination: LogDestination,
+    retention_days: u16,
 }
 
 impl Logger {
     pub fn from_settings(settings: &RuntimeSettings) -> Self {
         Self {
-            log_level: settings.log_level,
-            log_destination: settings.log_destination.clone(),
+            level: settings.log_level,
+            format: settings.log_format,
+            destination: settings.log_destination.clone(),
+            retention_days: settings.log_retention_days,
         }
     }
 
     pub fn setup(self) -> Result<(), LogsError> {
-        if self.log_level.eq(&LevelFilter::Off) {
+        if self.level.eq(&LevelFilter::Off) {
             return Ok(());
         }
 
-        let dispatcher = fern::Dispatch::new().level(self.log_level).format(
-            move |out, message, record| {
-                let time = Local::now();
-                out.finish(format_args!(
-                    "[{:0>2}-{:0>2}-{:0>2} {:0>2}:{:0>2} {}] {}",
-                    time.year(),
-                    time.month(),
-                    time.day(),
-                    time.hour(),
-                    time.minute(),
-                    record.level(),
-                    message
-                ));
-            },
-        );
+        let dispatcher = fern::Dispatch::new().level(self.level);
+        let dispatcher = match self.format {
+            LogFormat::Text => dispatcher.format(format::text),
+            LogFormat::Json => dispatcher.format(format::json),
+        };
 
-        let dispatcher = match self.log_destination {
+        let dispatcher = match &self.destination {
             LogDestination::Stdout => dispatcher.chain(std::io::stdout()),
             LogDestination::Directory(directory) => {
-                let file_path = Self::path(&directory);
-                let file = fern::log_file(file_path).map_err(LogsError::IO)?;
+                // A new file every day, named by local date
+                let file = fern::DateBased::new(
+                    directory.join(""),
+                    format!("{FILE_DATE_FORMAT}.log"),
+                );
                 dispatcher.chain(file)
             },
         };
 
-        dispatcher.apply().map_err(LogsError::SetLoggerError)
+        dispatcher.apply().map_err(LogsError::SetLoggerError)?;
+
+        if let LogDestination::Directory(directory) = self.destination {
+            Self::keep_pruning(directory, self.retention_days);
+        }
+
+        Ok(())
     }
 
-    fn path(directory: &Path) -> PathBuf {
-        let name = Self::generate_file_name();
-        directory.join(name)
+    // Prunes now and then once a day, for as long as the process runs. 0 keeps every file.
+    fn keep_pruning(directory: PathBuf, retention_days: u16) {
+        const DAY: Duration = Duration::from_hours(24);
+
+        if retention_days == 0 {
+            return;
+        }
+
+        std::thread::spawn(move || {
+            loop {
+                match Self::prune(&directory, retention_days) {
+                    Ok(0) => {},
+                    Ok(removed) => log::info!("Removed {removed} old log files."),
+                    Err(error) => log::warn!("Log retention. {error}"),
+                }
+                std::thread::sleep(DAY);
+            }
+        });
     }
 
-    fn generate_file_name() -> String {
-        let now = Local::now();
-        let date = format!(
-            "{year:04}-{day:02}-{month:02}",
-            year = now.year(),
-            day = now.day(),
-            month = now.month(),
-        );
+    // Only daily files this logger writes are considered, anything else is left alone
+    fn prune(directory: &Path, retention_days: u16) -> Result<usize, LogsError> {
+        let oldest_kept =
+            Local::now().date_naive() - chrono::Days::new(retention_days.into());
+
+        let mut removed = 0;
+        for entry in std::fs::read_dir(directory).map_err(LogsError::IO)? {
+            let path = entry.map_err(LogsError::IO)?.path();
+            if path.extension().is_none_or(|extension| extension != "log") {
+                continue;
+            }
+
+            let date = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| NaiveDate::parse_from_str(stem, FILE_DATE_FORMAT).ok());
+            if date.is_some_and(|date| date < oldest_kept) {
+                std::fs::remove_file(&path).map_err(LogsError::IO)?;
+                removed += 1;
+            }
+        }
 
-        format!("{date}.log")
+        Ok(removed)
     }
 }
 
@@ -0,0 +1,26 @@
+use serde_json::Value;
+use std::future::Future;
+
+tokio::task_local! {
+    static FIELDS: Vec<(&'static str, Value)>;
+}
+
+// Adds `fields` to every line logged while `future` runs, including from `scraper`,
+// which doesn't know about them. A nested scope keeps the outer fields.
+pub async fn scope<F: Future>(
+    fields: Vec<(&'static str, Value)>, future: F,
+) -> F::Output {
+    let mut all = FIELDS.try_with(Clone::clone).unwrap_or_default();
+    all.extend(fields);
+
+    FIELDS.scope(all, future).await
+}
+
+// Outside any scope, or on a thread that isn't a task, there's nothing to visit
+pub fn visit(mut visitor: impl FnMut(&'static str, &Value)) {
+    let _ = FIELDS.try_with(|fields| {
+        for (key, value) in fields {
+            visitor(key, value);
+        }
+    });
+}
@@ -0,0 +1,70 @@
+use crate::logs::context;
+use chrono::{Local, SecondsFormat};
+use log::Record;
+use log::kv::{Error, Key, Value, VisitSource};
+use serde_json::Map;
+use std::fmt::{Arguments, Write};
+
+// `[2026-07-14 09:30 INFO] Message. run=12 stage=institutions`
+pub fn text(out: fern::FormatCallback, message: &Arguments, record: &Record) {
+    let mut line = format!(
+        "[{} {}] {message}",
+        Local::now().format("%Y-%m-%d %H:%M"),
+        record.level()
+    );
+
+    context::visit(|key, value| {
+        let _ = write!(line, " {key}={value}");
+    });
+    let _ = record.key_values().visit(&mut TextFields(&mut line));
+
+    out.finish(format_args!("{line}"));
+}
+
+// One JSON object per line: timestamp, level, target and message, plus context fields and
+// the record's own key-values, which win on a clash
+pub fn json(out: fern::FormatCallback, message: &Arguments, record: &Record) {
+    let mut line = Map::new();
+    line.insert(
+        String::from("timestamp"),
+        Local::now()
+            .to_rfc3339_opts(SecondsFormat::Millis, false)
+            .into(),
+    );
+    line.insert(String::from("level"), record.level().as_str().into());
+    line.insert(String::from("target"), record.target().into());
+    line.insert(String::from("message"), message.to_string().into());
+
+    context::visit(|key, value| {
+        line.insert(key.to_string(), value.clone());
+    });
+    let _ = record.key_values().visit(&mut JsonFields(&mut line));
+
+    out.finish(format_args!("{}", serde_json::Value::Object(line)));
+}
+
+struct TextFields<'a>(&'a mut String);
+
+impl<'kvs> VisitSource<'kvs> for TextFields<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+        write!(self.0, " {key}={value}").map_err(|_| Error::msg("formatting"))
+    }
+}
+
+struct JsonFields<'a>(&'a mut Map<String, serde_json::Value>);
+
+impl<'kvs> VisitSource<'kvs> for JsonFields<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+        // Numbers and booleans stay typed so they can be filtered on
+        let value = value
+            .to_i64()
+            .map(serde_json::Value::from)
+            .or_else(|| value.to_u64().map(serde_json::Value::from))
+            .or_else(|| value.to_f64().map(serde_json::Value::from))
+            .or_else(|| value.to_bool().map(serde_json::Value::from))
+            .unwrap_or_else(|| value.to_string().into());
+
+        self.0.insert(key.to_string(), value);
+        Ok(())
+    }
+}
@@ -1,54 +1,27 @@
-use crate::config::Config;
-use crate::database::Database;
-use crate::logs::Logger;
-use crate::settings::RuntimeSettings;
-use scraper::Scraper;
+use crate::cli::Cli;
+use clap::Parser;
+use std::process::ExitCode;
 
 #[tokio::main]
-async fn main() -> () {
-    let config = Config::from_file().unwrap_or_else(|error| {
-        eprintln!("Error occurred. {error}");
-        std::process::exit(1);
-    });
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    let runtime_settings = RuntimeSettings::try_from(config).unwrap_or_else(|error| {
-        eprintln!("Error occurred. {error}");
-        std::process::exit(1);
-    });
-
-    Logger::from_settings(&runtime_settings)
-        .setup()
-        .unwrap_or_else(|error| {
-            eprintln!("Error occurred. {error}");
-            std::process::exit(1);
-        });
-
-    log::info!("Configuration successfully loaded.");
-    log::info!("Runtime settings: {runtime_settings:?}");
-    log::info!("Logger successfully initialized.");
-
-    let db = Database::init(&runtime_settings)
-        .await
-        .unwrap_or_else(|error| {
-            eprintln!("Error occurred. {error}");
-            std::process::exit(1);
-        });
-
-    log::info!("Database successfully initialized.");
-
-    log::info!("Starting process...");
-
-    Scraper::new(&db.pool)
-        .process()
-        .await
-        .unwrap_or_else(|error| {
+    match cli.run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
             eprintln!("Error occurred. {error}");
-            std::process::exit(1);
-        });
+            error.exit_status().into()
+        },
+    }
 }
 
+mod api;
+mod cli;
 mod config;
 mod database;
 mod errors;
 mod logs;
+mod scheduler;
 mod settings;
+mod shutdown;
+mod telemetry;
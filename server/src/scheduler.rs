@@ -0,0 +1,162 @@
+use crate::logs;
+use crate::scheduler::errors::SchedulerError;
+use crate::scheduler::history::RunHistory;
+use crate::scheduler::lock::ScrapeLock;
+use chrono::{DateTime, Local, Timelike};
+use croner::Cron;
+use model::campaign::Campaign;
+use scraper::Scraper;
+use sqlx::PgPool;
+use std::hash::{BuildHasher, Hasher, RandomState};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+pub mod errors;
+pub mod history;
+pub mod lock;
+
+// `schedule` from config: a 5-field cron pattern in the server's local time, plus up to
+// `max_jitter` of random delay so instances sharing a schedule don't all hit EDBO at once.
+#[derive(Clone)]
+pub struct Schedule {
+    pub cron: Cron,
+    pub max_jitter: Duration,
+}
+
+// `Cron`'s own `Debug` dumps every bitfield into the settings log line
+impl std::fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Schedule")
+            .field("cron", &self.cron.as_str())
+            .field("max_jitter", &self.max_jitter)
+            .finish()
+    }
+}
+
+impl Schedule {
+    fn jitter(&self) -> Duration {
+        let max = self.max_jitter.as_secs();
+        if max == 0 {
+            return Duration::ZERO;
+        }
+
+        // `RandomState` is seeded randomly per instance, enough for spreading out starts
+        let random = RandomState::new().build_hasher().finish();
+        Duration::from_secs(random % max.saturating_add(1))
+    }
+}
+
+pub struct Scheduler {
+    pool: PgPool,
+    campaign: Campaign,
+    schedule: Schedule,
+    shutdown: CancellationToken,
+}
+
+impl Scheduler {
+    pub fn new(
+        pool: &PgPool, campaign: Campaign, schedule: Schedule,
+        shutdown: CancellationToken,
+    ) -> Self {
+        Self {
+            pool: pool.clone(),
+            campaign,
+            schedule,
+            shutdown,
+        }
+    }
+
+    // Runs until shutdown or until the pattern has no next occurrence. A failed cycle is
+    // logged and recorded, and the next one still runs. A shutdown mid-cycle interrupts it.
+    pub async fn run(self) -> Result<(), SchedulerError> {
+        log::info!(
+            "Scheduler started: \"{}\", up to {}s of jitter.",
+            self.schedule.cron,
+            self.schedule.max_jitter.as_secs()
+        );
+
+        loop {
+            let now = Local::now();
+            // Otherwise the fraction of a second carries over into every occurrence
+            let whole_second = now.with_nanosecond(0).unwrap_or(now);
+            let next = self
+                .schedule
+                .cron
+                .find_next_occurrence(&whole_second, false)
+                .map_err(SchedulerError::NextOccurrence)?;
+
+            let jitter = self.schedule.jitter();
+            log::info!("Next cycle at {next}, {}s of jitter.", jitter.as_secs());
+
+            // Computed from `now` every time, so a cycle that overran its slot skips the
+            // missed ones instead of running them back to back.
+            let delay = (next - now).to_std().unwrap_or_default() + jitter;
+            if self
+                .shutdown
+                .run_until_cancelled(tokio::time::sleep(delay))
+                .await
+                .is_none()
+            {
+                break;
+            }
+
+            match self.cycle(next).await {
+                Err(error) if error.is_cancelled() => {
+                    log::warn!("Scheduled cycle interrupted by shutdown.");
+                },
+                Err(error) => log::error!("Scheduled cycle failed. {error}"),
+                Ok(()) => {},
+            }
+            if self.shutdown.is_cancelled() {
+                break;
+            }
+        }
+
+        log::info!("Scheduler stopped.");
+        Ok(())
+    }
+
+    async fn cycle(&self, scheduled_at: DateTime<Local>) -> Result<(), SchedulerError> {
+        let history = RunHistory::new(&self.pool, self.campaign);
+
+        let Some(lock) = ScrapeLock::try_acquire(&self.pool).await? else {
+            log::warn!("Another instance is scraping, cycle skipped.");
+            return history.skipped(scheduled_at).await;
+        };
+
+        // Whoever ran them held the lock, so they're not running anymore
+        history.interrupt_stale().await?;
+        let run = history.start(scheduled_at).await?;
+
+        let fields = vec![
+            ("run", run.into()),
+            ("campaign", i16::from(self.campaign).into()),
+        ];
+        let outcome = logs::context::scope(fields, self.stages(&history, run)).await;
+        let recorded = history.finish(run, outcome.as_ref().err()).await;
+        lock.release().await?;
+        recorded?;
+
+        if outcome.is_ok() {
+            log::info!("Scheduled cycle {run} finished.");
+        }
+        outcome
+    }
+
+    // Scrape, place, publish. Each stage replaces what the API reads in one transaction,
+    // so it keeps serving the previous complete results while a cycle runs.
+    async fn stages(
+        &self, history: &RunHistory<'_>, run: i32,
+    ) -> Result<(), SchedulerError> {
+        Scraper::new(&self.pool, self.campaign)
+            .with_shutdown(self.shutdown.clone())
+            .scrape_institutions(true)
+            .await?;
+        history.checkpoint(run, "institutions").await?;
+
+        // Placement and publishing its results come with the `placement` crate
+        log::info!("Placement isn't implemented yet, cycle ends after scraping.");
+
+        Ok(())
+    }
+}
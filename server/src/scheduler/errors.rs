@@ -0,0 +1,27 @@
+use croner::errors::CronError;
+use scraper::{ErrorKind, ScraperError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SchedulerError {
+    #[error("Schedule has no next occurrence. {0}")]
+    NextOccurrence(CronError),
+
+    #[error("Another instance is scraping.")]
+    Busy,
+
+    #[error("Scrape lock. {0}")]
+    Lock(sqlx::Error),
+
+    #[error("Run history \"{0}\". {1}")]
+    History(&'static str, sqlx::Error),
+
+    #[error("Scraper. {0}")]
+    Scraper(#[from] ScraperError),
+}
+
+impl SchedulerError {
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Scraper(error) if error.kind() == ErrorKind::Cancelled)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::scheduler::errors::SchedulerError;
+use crate::telemetry;
+use chrono::{DateTime, Local};
+use model::campaign::Campaign;
+use sqlx::PgPool;
+use strum_macros::IntoStaticStr;
+
+// `scheduler.run.status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+enum RunStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Skipped,
+    Interrupted,
+}
+
+pub struct RunHistory<'a> {
+    pool: &'a PgPool,
+    campaign: Campaign,
+}
+
+impl<'a> RunHistory<'a> {
+    pub const fn new(pool: &'a PgPool, campaign: Campaign) -> Self {
+        Self { pool, campaign }
+    }
+
+    pub async fn start(
+        &self, scheduled_at: DateTime<Local>,
+    ) -> Result<i32, SchedulerError> {
+        let status: &str = RunStatus::Running.into();
+
+        sqlx::query_scalar!(
+            "INSERT INTO scheduler.run (campaign_year, scheduled_at, status)
+             VALUES ($1, $2, $3) RETURNING id",
+            i16::from(self.campaign),
+            scheduled_at,
+            status,
+        )
+        .fetch_one(self.pool)
+        .await
+        .map_err(|error| SchedulerError::History("start", error))
+    }
+
+    pub async fn finish(
+        &self, run: i32, error: Option<&SchedulerError>,
+    ) -> Result<(), SchedulerError> {
+        let status: &str = match error {
+            Some(error) if error.is_cancelled() => RunStatus::Interrupted,
+            Some(_) => RunStatus::Failed,
+            None => RunStatus::Succeeded,
+        }
+        .into();
+
+        sqlx::query!(
+            "UPDATE scheduler.run SET status = $2, error = $3, finished_at = now()
+             WHERE id = $1",
+            run,
+            status,
+            error.map(ToString::to_string),
+        )
+        .execute(self.pool)
+        .await
+        .map_err(|error| SchedulerError::History("finish", error))?;
+
+        metrics::counter!(telemetry::SCHEDULER_RUNS, "status" => status).increment(1);
+        Ok(())
+    }
+
+    // The last stage that committed, written as soon as it has, so even a run that never
+    // reaches `finish` shows how far it got
+    pub async fn checkpoint(&self, run: i32, stage: &str) -> Result<(), SchedulerError> {
+        sqlx::query!(
+            "UPDATE scheduler.run SET checkpoint = $2 WHERE id = $1",
+            run,
+            stage,
+        )
+        .execute(self.pool)
+        .await
+        .map_err(|error| SchedulerError::History("checkpoint", error))?;
+
+        Ok(())
+    }
+
+    pub async fn skipped(
+        &self, scheduled_at: DateTime<Local>,
+    ) -> Result<(), SchedulerError> {
+        let status: &str = RunStatus::Skipped.into();
+
+        sqlx::query!(
+            "INSERT INTO scheduler.run (campaign_year, scheduled_at, finished_at, status)
+             VALUES ($1, $2, now(), $3)",
+            i16::from(self.campaign),
+            scheduled_at,
+            status,
+        )
+        .execute(self.pool)
+        .await
+        .map_err(|error| SchedulerError::History("skipped", error))?;
+
+        metrics::counter!(telemetry::SCHEDULER_RUNS, "status" => status).increment(1);
+        Ok(())
+    }
+
+    // Runs left `running` by an instance that stopped mid-cycle. Only safe to call while
+    // holding the scrape lock.
+    pub async fn interrupt_stale(&self) -> Result<(), SchedulerError> {
+        let running: &str = RunStatus::Running.into();
+        let interrupted: &str = RunStatus::Interrupted.into();
+
+        let stale = sqlx::query!(
+            "UPDATE scheduler.run
+             SET status = $2, finished_at = now(), error = 'Interrupted, the instance stopped.'
+             WHERE status = $1",
+            running,
+            interrupted,
+        )
+        .execute(self.pool)
+        .await
+        .map_err(|error| SchedulerError::History("interrupt stale", error))?
+        .rows_affected();
+
+        if stale > 0 {
+            log::warn!("Marked {stale} runs left running as interrupted.");
+        }
+
+        Ok(())
+    }
+}
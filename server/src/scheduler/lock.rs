@@ -0,0 +1,36 @@
+use crate::scheduler::errors::SchedulerError;
+use sqlx::{Connection, PgConnection, PgPool};
+
+// Session-level Postgres advisory lock, shared by every instance on the database, so two
+// of them never scrape at once. Arbitrary, only has to stay the same.
+const KEY: i64 = 0x6162_6974_7363_7270;
+
+// Holds its own connection, detached from the pool: a session lock returned to the pool
+// would stay held by whoever borrows that connection next. Dropping it without
+// `release` closes the connection, which releases the lock too.
+pub struct ScrapeLock {
+    connection: PgConnection,
+}
+
+impl ScrapeLock {
+    // `None` when another instance holds it
+    pub async fn try_acquire(pool: &PgPool) -> Result<Option<Self>, SchedulerError> {
+        let mut connection = pool.acquire().await.map_err(SchedulerError::Lock)?.detach();
+
+        let acquired = sqlx::query_scalar!("SELECT pg_try_advisory_lock($1)", KEY)
+            .fetch_one(&mut connection)
+            .await
+            .map_err(SchedulerError::Lock)?;
+
+        Ok(acquired.unwrap_or(false).then_some(Self { connection }))
+    }
+
+    pub async fn release(mut self) -> Result<(), SchedulerError> {
+        sqlx::query_scalar!("SELECT pg_advisory_unlock($1)", KEY)
+            .fetch_one(&mut self.connection)
+            .await
+            .map_err(SchedulerError::Lock)?;
+
+        self.connection.close().await.map_err(SchedulerError::Lock)
+    }
+}
@@ -1,28 +1,93 @@
 use crate::config::Config;
 use crate::logs;
-use crate::logs::LogDestination;
+use crate::logs::{LogDestination, LogFormat};
+use crate::scheduler::Schedule;
+use croner::Cron;
 use log::LevelFilter;
+use model::campaign::Campaign;
+use std::net::SocketAddr;
+use std::time::Duration;
 use thiserror::Error;
 
+const DEFAULT_LISTEN_ADDRESS: &str = "127.0.0.1:8080";
+const DEFAULT_SCHEDULE_JITTER_SECONDS: u64 = 300;
+const DEFAULT_FRESHNESS_MAX_AGE_SECONDS: u64 = 24 * 60 * 60;
+const DEFAULT_SHUTDOWN_TIMEOUT_SECONDS: u64 = 30;
+
 #[derive(Debug)]
 pub struct RuntimeSettings {
-    pub database_url: String,
+    // Only `serve` can do without it, given `api_database_url`
+    pub database_url: Option<Secret>,
+    pub legacy_database_url: Option<Secret>,
+    pub scraper_database_url: Option<Secret>,
+    pub api_database_url: Option<Secret>,
+    pub campaign: Campaign,
+    pub listen_address: SocketAddr,
+    pub schedule: Option<Schedule>,
+    pub freshness_max_age: Duration,
+    pub shutdown_timeout: Duration,
     pub log_level: LevelFilter,
+    pub log_format: LogFormat,
     pub log_destination: LogDestination,
+    pub log_retention_days: u16,
 }
 
 impl TryFrom<Config> for RuntimeSettings {
     type Error = RuntimeSettingsError;
 
     fn try_from(value: Config) -> Result<Self, Self::Error> {
-        if value.database_url.is_empty() {
-            return Err(Self::Error::DatabaseUrlEmpty);
-        }
-        let database_url = value.database_url;
+        let database_url = value.database_url.filter(|url| !url.is_empty()).map(Secret);
+
+        let legacy_database_url = value
+            .legacy_database_url
+            .filter(|url| !url.is_empty())
+            .map(Secret);
+        let scraper_database_url = value
+            .scraper_database_url
+            .filter(|url| !url.is_empty())
+            .map(Secret);
+        let api_database_url = value
+            .api_database_url
+            .filter(|url| !url.is_empty())
+            .map(Secret);
+
+        let campaign = match value.campaign {
+            Some(year) => Campaign::try_from(year)
+                .map_err(|_| Self::Error::UnknownCampaign(year))?,
+            None => Campaign::CURRENT,
+        };
+
+        let listen_address = value
+            .listen_address
+            .as_deref()
+            .unwrap_or(DEFAULT_LISTEN_ADDRESS);
+        let listen_address = listen_address
+            .parse()
+            .map_err(|_| Self::Error::InvalidListenAddress(listen_address.to_string()))?;
+
+        let schedule = value
+            .schedule
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| {
+                let cron = pattern.parse::<Cron>().map_err(|error| {
+                    Self::Error::InvalidSchedule(pattern.clone(), error.to_string())
+                })?;
+                let jitter = value
+                    .schedule_jitter_seconds
+                    .unwrap_or(DEFAULT_SCHEDULE_JITTER_SECONDS);
+
+                Ok(Schedule {
+                    cron,
+                    max_jitter: Duration::from_secs(jitter),
+                })
+            })
+            .transpose()?;
 
         let log_level: LevelFilter =
             value.log_level.map_or(logs::DEFAULT_LOG_LEVEL, Into::into);
 
+        let log_format = value.log_format.unwrap_or_default();
+
         let log_destination = match value.log_directory {
             Some(directory) => {
                 if !directory.is_dir() {
@@ -35,17 +100,72 @@ impl TryFrom<Config> for RuntimeSettings {
 
         Ok(Self {
             database_url,
+            legacy_database_url,
+            scraper_database_url,
+            api_database_url,
+            campaign,
+            listen_address,
+            schedule,
+            freshness_max_age: Duration::from_secs(
+                value
+                    .freshness_max_age_seconds
+                    .unwrap_or(DEFAULT_FRESHNESS_MAX_AGE_SECONDS),
+            ),
+            shutdown_timeout: Duration::from_secs(
+                value
+                    .shutdown_timeout_seconds
+                    .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_SECONDS),
+            ),
             log_level,
+            log_format,
             log_destination,
+            log_retention_days: value
+                .log_retention_days
+                .unwrap_or(logs::DEFAULT_LOG_RETENTION_DAYS),
         })
     }
 }
 
+// Connection URLs carry passwords: kept out of `Debug`, so out of logs and `check-config`
+pub struct Secret(String);
+
+impl Secret {
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum RuntimeSettingsError {
     #[error("Database URL field is empty.")]
     DatabaseUrlEmpty,
 
+    #[error("Legacy database URL field is empty.")]
+    LegacyDatabaseUrlEmpty,
+
+    #[error("API database URL field is empty, the API never connects as the owner.")]
+    ApiDatabaseUrlEmpty,
+
+    #[error(
+        "Scraper database URL field is empty, and so is the owner's to fall back on."
+    )]
+    ScraperDatabaseUrlEmpty,
+
+    #[error("Unknown campaign year: {0}.")]
+    UnknownCampaign(i16),
+
+    #[error("Invalid listen address: \"{0}\".")]
+    InvalidListenAddress(String),
+
+    #[error("Invalid schedule \"{0}\". {1}")]
+    InvalidSchedule(String, String),
+
     #[error("Provided log destination is not a directory.")]
     LogPathNotDirectory,
 }
@@ -0,0 +1,51 @@
+use tokio_util::sync::CancellationToken;
+
+// Cancelled on SIGTERM or Ctrl+C. Whatever runs long watches it: the API stops accepting
+// and drains, the scheduler ends after an interrupted cycle, the scraper rolls back.
+pub fn listen() -> CancellationToken {
+    let shutdown = CancellationToken::new();
+
+    let cancel = shutdown.clone();
+    tokio::spawn(async move {
+        signal().await;
+        log::info!("Shutdown requested, stopping...");
+        cancel.cancel();
+    });
+
+    shutdown
+}
+
+async fn signal() {
+    let interrupt = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            log::warn!("Can't listen for Ctrl+C. {error}");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    tokio::select! {
+        () = interrupt => {},
+        () = terminate() => {},
+    }
+}
+
+#[cfg(unix)]
+async fn terminate() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut terminate) => {
+            terminate.recv().await;
+        },
+        Err(error) => {
+            log::warn!("Can't listen for SIGTERM. {error}");
+            std::future::pending::<()>().await;
+        },
+    }
+}
+
+// Services on other platforms are stopped with Ctrl+C's equivalent
+#[cfg(not(unix))]
+async fn terminate() {
+    std::future::pending::<()>().await;
+}
@@ -0,0 +1,81 @@
+use axum::extract::{MatchedPath, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use metrics::{Unit, describe_counter, describe_histogram};
+use metrics_exporter_prometheus::{
+    BuildError, Matcher, PrometheusBuilder, PrometheusHandle,
+};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+pub const PATH: &str = "/metrics";
+
+pub const API_REQUEST_DURATION: &str = "abit_api_request_duration_seconds";
+pub const SCHEDULER_RUNS: &str = "abit_scheduler_runs_total";
+
+// Seconds, for every `*_seconds` histogram: API requests sit at the low end, EDBO
+// requests and scraper stages further up.
+const BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0,
+    900.0,
+];
+
+// Drains recorded histogram samples, so memory doesn't grow between scrapes
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+// Installs the global recorder `/metrics` renders from, for `scraper` too. Only `serve`
+// does: without it, recording anywhere is a no-op.
+pub fn install() -> Result<PrometheusHandle, TelemetryError> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix(String::from("_seconds")), BUCKETS)?
+        .install_recorder()?;
+
+    describe_histogram!(
+        API_REQUEST_DURATION,
+        Unit::Seconds,
+        "API request latency by method, route and status."
+    );
+    describe_counter!(SCHEDULER_RUNS, "Scheduled cycles by final status.");
+    scraper::describe_metrics();
+
+    let upkeep = handle.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(UPKEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            upkeep.run_upkeep();
+        }
+    });
+
+    Ok(handle)
+}
+
+// Labelled by the route pattern, not the requested path, so ids don't each become a
+// series. A route layer: requests that match no route aren't measured.
+pub async fn track_api(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+    let method = request.method().to_string();
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    metrics::histogram!(
+        API_REQUEST_DURATION,
+        "method" => method,
+        "route" => route,
+        "status" => response.status().as_str().to_string()
+    )
+    .record(started.elapsed());
+
+    response
+}
+
+#[derive(Debug, Error)]
+pub enum TelemetryError {
+    #[error("Failed to install metrics recorder. {0}")]
+    Install(#[from] BuildError),
+}